@@ -1,13 +1,18 @@
+use futures::Stream;
 use reqwest::header;
 use serde::{Deserialize, Serialize};
 
 use crate::{
     error::Error,
-    models::{Account as AccountModel, AccountHistory},
+    models::{
+        Account as AccountModel, AccountAddress, AccountHistory, AccountUpdate, AssociatedAccount,
+        CreatedAccount, ManualAccount,
+    },
+    paginator::{paginate, MAX_PAGE_SIZE},
     Client,
 };
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Account {
     login_name: String,
     client: Client,
@@ -58,6 +63,42 @@ impl Account {
         }
     }
 
+    /// Fetches real time balances for bank accounts. Balances are refreshed on
+    /// demand, so call this again until each account's `refresh_status` is no
+    /// longer `IN_PROGRESS` to get current figures.
+    pub async fn get_latest_balances(
+        &mut self,
+        params: LatestBalanceParams<'_>,
+    ) -> Result<AccountResponse, Error> {
+        let access_token = self.client.ensure_token(&self.login_name).await?;
+        let (endpoint, api_version, http_client) = {
+            let state = self.client.state.read().unwrap();
+            (
+                // endpoint
+                format!("{}/{}", state.api_endpoint, "accounts/latestBalances"),
+                state.api_version.clone(),
+                state.http_client.clone(),
+            )
+        };
+
+        let res = http_client
+            .get(endpoint)
+            .header("Api-Version", api_version)
+            .header(header::AUTHORIZATION, format!("Bearer {access_token}"))
+            .query(&[
+                ("providerAccountId", params.provider_account_id.to_string()),
+                ("accountId", params.account_ids.join(",")),
+            ])
+            .send()
+            .await?;
+
+        if res.status().is_success() {
+            Ok(res.json().await?)
+        } else {
+            Err(Error::Api(res.json().await?))
+        }
+    }
+
     pub async fn get_historical_balances(
         &mut self,
         params: AccountHistoricalBalanceParams<'_>,
@@ -104,6 +145,211 @@ impl Account {
         }
     }
 
+    /// Streams the historical balances matching `params`, fetching further pages
+    /// as the stream is polled. Each page yields one `AccountHistory` per
+    /// account, so an account can show up more than once.
+    pub fn get_historical_balances_stream<'a>(
+        &self,
+        params: AccountHistoricalBalanceParams<'a>,
+    ) -> impl Stream<Item = Result<AccountHistory, Error>> + 'a {
+        let this = self.clone();
+        paginate(
+            params.skip.unwrap_or(0),
+            params.top.unwrap_or(MAX_PAGE_SIZE),
+            move |skip, top| {
+                let mut this = this.clone();
+                async move {
+                    let params = AccountHistoricalBalanceParams {
+                        skip: Some(skip),
+                        top: Some(top),
+                        ..params
+                    };
+                    let page = this
+                        .get_historical_balances(params)
+                        .await?
+                        .account
+                        .unwrap_or_default();
+                    // skip/top page through balances rather than accounts
+                    let count = page
+                        .iter()
+                        .map(|a| a.historical_balances.as_ref().map_or(0, Vec::len))
+                        .sum();
+                    Ok((page, count))
+                }
+            },
+        )
+    }
+
+    pub async fn add_manual(
+        &mut self,
+        account: ManualAccount,
+    ) -> Result<CreatedAccountResponse, Error> {
+        let access_token = self.client.ensure_token(&self.login_name).await?;
+
+        let (endpoint, api_version, http_client) = {
+            let state = self.client.state.read().unwrap();
+            (
+                // endpoint
+                format!("{}/{}", state.api_endpoint, "accounts"),
+                state.api_version.clone(),
+                state.http_client.clone(),
+            )
+        };
+
+        let req = ManualAccountRequest { account };
+        let res = http_client
+            .post(endpoint)
+            .header("Api-Version", api_version)
+            .header(header::AUTHORIZATION, format!("Bearer {access_token}"))
+            .json(&req)
+            .send()
+            .await?;
+
+        if res.status().is_success() {
+            Ok(res.json().await?)
+        } else {
+            Err(Error::Api(res.json().await?))
+        }
+    }
+
+    pub async fn update(
+        &mut self,
+        account_id: String,
+        account: AccountUpdate,
+    ) -> Result<(), Error> {
+        let access_token = self.client.ensure_token(&self.login_name).await?;
+
+        let (endpoint, api_version, http_client) = {
+            let state = self.client.state.read().unwrap();
+            (
+                // endpoint
+                format!("{}/{}/{}", state.api_endpoint, "accounts", account_id),
+                state.api_version.clone(),
+                state.http_client.clone(),
+            )
+        };
+
+        let req = AccountUpdateRequest { account };
+        let res = http_client
+            .put(endpoint)
+            .header("Api-Version", api_version)
+            .header(header::AUTHORIZATION, format!("Bearer {access_token}"))
+            .json(&req)
+            .send()
+            .await?;
+
+        if res.status().is_success() {
+            Ok(())
+        } else {
+            Err(Error::Api(res.json().await?))
+        }
+    }
+
+    /// Checks that `address` can be valued by Yodlee before it is used for a
+    /// real estate account with the `SYSTEM` valuation type.
+    pub async fn evaluate_address(
+        &mut self,
+        address: AccountAddress,
+    ) -> Result<EvaluateAddressResponse, Error> {
+        let access_token = self.client.ensure_token(&self.login_name).await?;
+
+        let (endpoint, api_version, http_client) = {
+            let state = self.client.state.read().unwrap();
+            (
+                // endpoint
+                format!("{}/{}", state.api_endpoint, "accounts/evaluateAddress"),
+                state.api_version.clone(),
+                state.http_client.clone(),
+            )
+        };
+
+        let req = EvaluateAddressRequest { address };
+        let res = http_client
+            .post(endpoint)
+            .header("Api-Version", api_version)
+            .header(header::AUTHORIZATION, format!("Bearer {access_token}"))
+            .json(&req)
+            .send()
+            .await?;
+
+        if res.status().is_success() {
+            Ok(res.json().await?)
+        } else {
+            Err(Error::Api(res.json().await?))
+        }
+    }
+
+    /// Lists the accounts in other provider accounts that belong to the same
+    /// user at the same institution, e.g. the old and new provider accounts
+    /// of an OAuth migration.
+    pub async fn get_associated_accounts(
+        &mut self,
+        provider_account_id: String,
+    ) -> Result<AssociatedAccountResponse, Error> {
+        let access_token = self.client.ensure_token(&self.login_name).await?;
+
+        let (endpoint, api_version, http_client) = {
+            let state = self.client.state.read().unwrap();
+            (
+                // endpoint
+                format!(
+                    "{}/{}/{}",
+                    state.api_endpoint, "accounts/associatedAccounts", provider_account_id
+                ),
+                state.api_version.clone(),
+                state.http_client.clone(),
+            )
+        };
+
+        let res = http_client
+            .get(endpoint)
+            .header("Api-Version", api_version)
+            .header(header::AUTHORIZATION, format!("Bearer {access_token}"))
+            .send()
+            .await?;
+
+        if res.status().is_success() {
+            Ok(res.json().await?)
+        } else {
+            Err(Error::Api(res.json().await?))
+        }
+    }
+
+    /// Migrates the accounts of a provider account to the provider's OAuth
+    /// site. Progress shows up in each account's `oauth_migration_status`.
+    pub async fn migrate_accounts(
+        &mut self,
+        provider_account_id: String,
+    ) -> Result<AccountMigrationResponse, Error> {
+        let access_token = self.client.ensure_token(&self.login_name).await?;
+
+        let (endpoint, api_version, http_client) = {
+            let state = self.client.state.read().unwrap();
+            (
+                // endpoint
+                format!(
+                    "{}/{}/{}",
+                    state.api_endpoint, "accounts/migrateAccounts", provider_account_id
+                ),
+                state.api_version.clone(),
+                state.http_client.clone(),
+            )
+        };
+
+        let res = http_client
+            .put(endpoint)
+            .header("Api-Version", api_version)
+            .header(header::AUTHORIZATION, format!("Bearer {access_token}"))
+            .send()
+            .await?;
+
+        if res.status().is_success() {
+            Ok(res.json().await?)
+        } else {
+            Err(Error::Api(res.json().await?))
+        }
+    }
+
     pub async fn delete(&mut self, account_id: String) -> Result<(), Error> {
         let access_token = self.client.ensure_token(&self.login_name).await?;
 
@@ -147,7 +393,13 @@ pub struct AccountResponse {
     pub account: Option<Vec<AccountModel>>,
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Copy)]
+pub struct LatestBalanceParams<'a> {
+    pub provider_account_id: &'a str,
+    pub account_ids: &'a [&'a str],
+}
+
+#[derive(Debug, Default, Clone, Copy)]
 pub struct AccountHistoricalBalanceParams<'a> {
     pub include_carry_forward: Option<bool>,
     pub from_date: Option<&'a str>,
@@ -163,3 +415,43 @@ pub struct AccountHistoricalBalanceParams<'a> {
 pub struct AccountHistoricalBalanceResponse {
     pub account: Option<Vec<AccountHistory>>,
 }
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct CreatedAccountResponse {
+    pub account: Option<Vec<CreatedAccount>>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EvaluateAddressResponse {
+    pub address: Option<Vec<AccountAddress>>,
+    pub is_valid_address: Option<bool>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssociatedAccountResponse {
+    pub associated_account: Option<Vec<AssociatedAccount>>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountMigrationResponse {
+    pub provider_id: Option<i64>,
+    pub provider_account_id: Option<i64>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct ManualAccountRequest {
+    account: ManualAccount,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct AccountUpdateRequest {
+    account: AccountUpdate,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct EvaluateAddressRequest {
+    address: AccountAddress,
+}
@@ -0,0 +1,202 @@
+use reqwest::header;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::Error,
+    models::{CategorizationRule as CategorizationRuleModel, CategorizationRuleRequest},
+    Client,
+};
+
+#[derive(Debug, Clone)]
+pub struct CategorizationRule {
+    login_name: String,
+    client: Client,
+}
+
+impl CategorizationRule {
+    pub fn new(client: Client, login_name: String) -> Self {
+        CategorizationRule { login_name, client }
+    }
+
+    pub async fn get_rules(&mut self) -> Result<Vec<CategorizationRuleModel>, Error> {
+        let access_token = self.client.ensure_token(&self.login_name).await?;
+        let (endpoint, api_version, http_client) = {
+            let state = self.client.state.read().unwrap();
+            (
+                // endpoint
+                format!("{}/{}", state.api_endpoint, "transactions/categories/rules"),
+                state.api_version.clone(),
+                state.http_client.clone(),
+            )
+        };
+
+        let res = http_client
+            .get(endpoint)
+            .header("Api-Version", api_version)
+            .header(header::AUTHORIZATION, format!("Bearer {access_token}"))
+            .send()
+            .await?;
+
+        if res.status().is_success() {
+            Ok(res.json().await?)
+        } else {
+            Err(Error::Api(res.json().await?))
+        }
+    }
+
+    pub async fn create(&mut self, rule: CategorizationRuleRequest) -> Result<(), Error> {
+        let access_token = self.client.ensure_token(&self.login_name).await?;
+        let (endpoint, api_version, http_client) = {
+            let state = self.client.state.read().unwrap();
+            (
+                // endpoint
+                format!("{}/{}", state.api_endpoint, "transactions/categories/rules"),
+                state.api_version.clone(),
+                state.http_client.clone(),
+            )
+        };
+
+        let req = CategorizationRuleRequestBody { rule };
+        let res = http_client
+            .post(endpoint)
+            .header("Api-Version", api_version)
+            .header(header::AUTHORIZATION, format!("Bearer {access_token}"))
+            .json(&req)
+            .send()
+            .await?;
+
+        if res.status().is_success() {
+            Ok(())
+        } else {
+            Err(Error::Api(res.json().await?))
+        }
+    }
+
+    pub async fn update(
+        &mut self,
+        rule_id: String,
+        rule: CategorizationRuleRequest,
+    ) -> Result<(), Error> {
+        let access_token = self.client.ensure_token(&self.login_name).await?;
+        let (endpoint, api_version, http_client) = {
+            let state = self.client.state.read().unwrap();
+            (
+                // endpoint
+                format!(
+                    "{}/{}/{}",
+                    state.api_endpoint, "transactions/categories/rules", rule_id
+                ),
+                state.api_version.clone(),
+                state.http_client.clone(),
+            )
+        };
+
+        let req = CategorizationRuleRequestBody { rule };
+        let res = http_client
+            .put(endpoint)
+            .header("Api-Version", api_version)
+            .header(header::AUTHORIZATION, format!("Bearer {access_token}"))
+            .json(&req)
+            .send()
+            .await?;
+
+        if res.status().is_success() {
+            Ok(())
+        } else {
+            Err(Error::Api(res.json().await?))
+        }
+    }
+
+    pub async fn delete(&mut self, rule_id: String) -> Result<(), Error> {
+        let access_token = self.client.ensure_token(&self.login_name).await?;
+        let (endpoint, api_version, http_client) = {
+            let state = self.client.state.read().unwrap();
+            (
+                // endpoint
+                format!(
+                    "{}/{}/{}",
+                    state.api_endpoint, "transactions/categories/rules", rule_id
+                ),
+                state.api_version.clone(),
+                state.http_client.clone(),
+            )
+        };
+
+        let res = http_client
+            .delete(endpoint)
+            .header("Api-Version", api_version)
+            .header(header::AUTHORIZATION, format!("Bearer {access_token}"))
+            .send()
+            .await?;
+
+        if res.status().is_success() {
+            Ok(())
+        } else {
+            Err(Error::Api(res.json().await?))
+        }
+    }
+
+    /// Runs a single rule against the user's existing transactions.
+    pub async fn run(&mut self, rule_id: String) -> Result<(), Error> {
+        let access_token = self.client.ensure_token(&self.login_name).await?;
+        let (endpoint, api_version, http_client) = {
+            let state = self.client.state.read().unwrap();
+            (
+                // endpoint
+                format!(
+                    "{}/{}/{}",
+                    state.api_endpoint, "transactions/categories/rules", rule_id
+                ),
+                state.api_version.clone(),
+                state.http_client.clone(),
+            )
+        };
+
+        let res = http_client
+            .post(endpoint)
+            .header("Api-Version", api_version)
+            .header(header::AUTHORIZATION, format!("Bearer {access_token}"))
+            .query(&[("action", "run")])
+            .send()
+            .await?;
+
+        if res.status().is_success() {
+            Ok(())
+        } else {
+            Err(Error::Api(res.json().await?))
+        }
+    }
+
+    /// Runs all of the user's rules against their existing transactions.
+    pub async fn run_all(&mut self) -> Result<(), Error> {
+        let access_token = self.client.ensure_token(&self.login_name).await?;
+        let (endpoint, api_version, http_client) = {
+            let state = self.client.state.read().unwrap();
+            (
+                // endpoint
+                format!("{}/{}", state.api_endpoint, "transactions/categories/rules"),
+                state.api_version.clone(),
+                state.http_client.clone(),
+            )
+        };
+
+        let res = http_client
+            .post(endpoint)
+            .header("Api-Version", api_version)
+            .header(header::AUTHORIZATION, format!("Bearer {access_token}"))
+            .query(&[("action", "run")])
+            .send()
+            .await?;
+
+        if res.status().is_success() {
+            Ok(())
+        } else {
+            Err(Error::Api(res.json().await?))
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CategorizationRuleRequestBody {
+    rule: CategorizationRuleRequest,
+}
@@ -0,0 +1,186 @@
+use reqwest::header;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::Error,
+    models::{EventName, EventSubscription},
+    Client,
+};
+
+/// Cobrand configuration. Every call here is made with the admin token, so the
+/// client must be open.
+#[derive(Debug, Clone)]
+pub struct Config {
+    client: Client,
+}
+
+impl Config {
+    pub fn new(client: Client) -> Self {
+        Config { client }
+    }
+
+    pub async fn get_event_subscriptions(
+        &mut self,
+        event_name: Option<EventName>,
+    ) -> Result<EventSubscriptionResponse, Error> {
+        let access_token = self
+            .client
+            .token_manager
+            .get_admin_token()
+            .ok_or(Error::NoToken)?;
+        let (endpoint, api_version, http_client) = {
+            let state = self.client.state.read().unwrap();
+            (
+                // endpoint
+                format!("{}/{}", state.api_endpoint, "configs/notifications/events"),
+                state.api_version.clone(),
+                state.http_client.clone(),
+            )
+        };
+
+        let res = http_client
+            .get(endpoint)
+            .header("Api-Version", api_version)
+            .header(header::AUTHORIZATION, format!("Bearer {access_token}"))
+            .query(&[("eventName", event_name.map(|e| e.as_str()))])
+            .send()
+            .await?;
+
+        if res.status().is_success() {
+            Ok(res.json().await?)
+        } else {
+            Err(Error::Api(res.json().await?))
+        }
+    }
+
+    pub async fn create_event_subscription(
+        &mut self,
+        event_name: EventName,
+        callback_url: String,
+    ) -> Result<(), Error> {
+        let access_token = self
+            .client
+            .token_manager
+            .get_admin_token()
+            .ok_or(Error::NoToken)?;
+        let (endpoint, api_version, http_client) = {
+            let state = self.client.state.read().unwrap();
+            (
+                // endpoint
+                format!(
+                    "{}/{}/{}",
+                    state.api_endpoint, "configs/notifications/events", event_name
+                ),
+                state.api_version.clone(),
+                state.http_client.clone(),
+            )
+        };
+
+        let req = EventSubscriptionRequest {
+            event: CallbackUrl { callback_url },
+        };
+        let res = http_client
+            .post(endpoint)
+            .header("Api-Version", api_version)
+            .header(header::AUTHORIZATION, format!("Bearer {access_token}"))
+            .json(&req)
+            .send()
+            .await?;
+
+        if res.status().is_success() {
+            Ok(())
+        } else {
+            Err(Error::Api(res.json().await?))
+        }
+    }
+
+    pub async fn update_event_subscription(
+        &mut self,
+        event_name: EventName,
+        callback_url: String,
+    ) -> Result<(), Error> {
+        let access_token = self
+            .client
+            .token_manager
+            .get_admin_token()
+            .ok_or(Error::NoToken)?;
+        let (endpoint, api_version, http_client) = {
+            let state = self.client.state.read().unwrap();
+            (
+                // endpoint
+                format!(
+                    "{}/{}/{}",
+                    state.api_endpoint, "configs/notifications/events", event_name
+                ),
+                state.api_version.clone(),
+                state.http_client.clone(),
+            )
+        };
+
+        let req = EventSubscriptionRequest {
+            event: CallbackUrl { callback_url },
+        };
+        let res = http_client
+            .put(endpoint)
+            .header("Api-Version", api_version)
+            .header(header::AUTHORIZATION, format!("Bearer {access_token}"))
+            .json(&req)
+            .send()
+            .await?;
+
+        if res.status().is_success() {
+            Ok(())
+        } else {
+            Err(Error::Api(res.json().await?))
+        }
+    }
+
+    pub async fn delete_event_subscription(&mut self, event_name: EventName) -> Result<(), Error> {
+        let access_token = self
+            .client
+            .token_manager
+            .get_admin_token()
+            .ok_or(Error::NoToken)?;
+        let (endpoint, api_version, http_client) = {
+            let state = self.client.state.read().unwrap();
+            (
+                // endpoint
+                format!(
+                    "{}/{}/{}",
+                    state.api_endpoint, "configs/notifications/events", event_name
+                ),
+                state.api_version.clone(),
+                state.http_client.clone(),
+            )
+        };
+
+        let res = http_client
+            .delete(endpoint)
+            .header("Api-Version", api_version)
+            .header(header::AUTHORIZATION, format!("Bearer {access_token}"))
+            .send()
+            .await?;
+
+        if res.status().is_success() {
+            Ok(())
+        } else {
+            Err(Error::Api(res.json().await?))
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct EventSubscriptionResponse {
+    pub event: Option<Vec<EventSubscription>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct EventSubscriptionRequest {
+    event: CallbackUrl,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CallbackUrl {
+    callback_url: String,
+}
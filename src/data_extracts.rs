@@ -0,0 +1,136 @@
+use reqwest::header;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::Error,
+    models::{DataExtractsUserData, EventName},
+    webhook::DataUpdatesEvent,
+    Client,
+};
+
+/// Incremental changes to user data, as announced by `DATA_UPDATES` events.
+/// Every call here is made with the admin token, so the client must be open.
+///
+/// Yodlee only keeps changes for the last 7 days, and `from_date` and
+/// `to_date` are ISO 8601 timestamps in UTC, e.g. `2023-05-02T08:00:00Z`.
+#[derive(Debug, Clone)]
+pub struct DataExtracts {
+    client: Client,
+}
+
+impl DataExtracts {
+    pub fn new(client: Client) -> Self {
+        DataExtracts { client }
+    }
+
+    /// Returns the users whose data changed between `from_date` and
+    /// `to_date`, the same as a `DATA_UPDATES` notification would.
+    pub async fn get_events(
+        &mut self,
+        params: DataExtractsEventParams<'_>,
+    ) -> Result<DataExtractsEventResponse, Error> {
+        let access_token = self
+            .client
+            .token_manager
+            .get_admin_token()
+            .ok_or(Error::NoToken)?;
+        let (endpoint, api_version, http_client) = {
+            let state = self.client.state.read().unwrap();
+            (
+                // endpoint
+                format!("{}/{}", state.api_endpoint, "dataExtracts/events"),
+                state.api_version.clone(),
+                state.http_client.clone(),
+            )
+        };
+
+        let res = http_client
+            .get(endpoint)
+            .header("Api-Version", api_version)
+            .header(header::AUTHORIZATION, format!("Bearer {access_token}"))
+            .query(&[
+                ("eventName", EventName::DataUpdates.as_str()),
+                ("fromDate", params.from_date),
+                ("toDate", params.to_date),
+            ])
+            .send()
+            .await?;
+
+        if res.status().is_success() {
+            Ok(res.json().await?)
+        } else {
+            Err(Error::Api(res.json().await?))
+        }
+    }
+
+    /// Returns the accounts, transactions, holdings and provider accounts of
+    /// a user that changed between `from_date` and `to_date`. Transactions
+    /// are paged with `skip` and `top`; `total_transactions_count` in the
+    /// response says how many there are in all.
+    pub async fn get_user_data(
+        &mut self,
+        params: DataExtractsUserDataParams<'_>,
+    ) -> Result<DataExtractsUserDataResponse, Error> {
+        let access_token = self
+            .client
+            .token_manager
+            .get_admin_token()
+            .ok_or(Error::NoToken)?;
+        let (endpoint, api_version, http_client) = {
+            let state = self.client.state.read().unwrap();
+            (
+                // endpoint
+                format!("{}/{}", state.api_endpoint, "dataExtracts/userData"),
+                state.api_version.clone(),
+                state.http_client.clone(),
+            )
+        };
+
+        let res = http_client
+            .get(endpoint)
+            .header("Api-Version", api_version)
+            .header(header::AUTHORIZATION, format!("Bearer {access_token}"))
+            .query(&[
+                ("loginName", Some(params.login_name.to_string())),
+                ("fromDate", Some(params.from_date.to_string())),
+                ("toDate", Some(params.to_date.to_string())),
+                ("skip", params.skip.map(|s| s.to_string())),
+                ("top", params.top.map(|s| s.to_string())),
+            ])
+            .send()
+            .await?;
+
+        if res.status().is_success() {
+            Ok(res.json().await?)
+        } else {
+            Err(Error::Api(res.json().await?))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct DataExtractsEventParams<'a> {
+    pub from_date: &'a str,
+    pub to_date: &'a str,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct DataExtractsUserDataParams<'a> {
+    pub login_name: &'a str,
+    pub from_date: &'a str,
+    pub to_date: &'a str,
+    pub skip: Option<u32>,
+    pub top: Option<u32>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct DataExtractsEventResponse {
+    pub event: Option<DataUpdatesEvent>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DataExtractsUserDataResponse {
+    pub total_transactions_count: Option<i64>,
+    pub user_data: Option<Vec<DataExtractsUserData>>,
+}
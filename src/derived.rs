@@ -0,0 +1,192 @@
+use reqwest::header;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::Error,
+    models::{DerivedHoldingSummary, DerivedNetworth, DerivedTransactionSummary},
+    Client,
+};
+
+#[derive(Debug, Clone)]
+pub struct Derived {
+    login_name: String,
+    client: Client,
+}
+
+impl Derived {
+    pub fn new(client: Client, login_name: String) -> Self {
+        Derived { login_name, client }
+    }
+
+    pub async fn get_networth(
+        &mut self,
+        params: DerivedNetworthParams<'_>,
+    ) -> Result<DerivedNetworthResponse, Error> {
+        let access_token = self.client.ensure_token(&self.login_name).await?;
+        let (endpoint, api_version, http_client) = {
+            let state = self.client.state.read().unwrap();
+            (
+                // endpoint
+                format!("{}/{}", state.api_endpoint, "derived/networth"),
+                state.api_version.clone(),
+                state.http_client.clone(),
+            )
+        };
+
+        let res = http_client
+            .get(endpoint)
+            .header("Api-Version", api_version)
+            .header(header::AUTHORIZATION, format!("Bearer {access_token}"))
+            .query(&[
+                ("accountIds", params.account_ids.map(|e| e.join(","))),
+                ("container", params.container.map(|s| s.to_string())),
+                ("fromDate", params.from_date.map(|s| s.to_string())),
+                ("toDate", params.to_date.map(|s| s.to_string())),
+                ("interval", params.interval.map(|s| s.to_string())),
+                ("include", params.include.map(|s| s.to_string())),
+                ("skip", params.skip.map(|s| s.to_string())),
+                ("top", params.top.map(|s| s.to_string())),
+            ])
+            .send()
+            .await?;
+
+        if res.status().is_success() {
+            Ok(res.json().await?)
+        } else {
+            Err(Error::Api(res.json().await?))
+        }
+    }
+
+    pub async fn get_holding_summary(
+        &mut self,
+        params: DerivedHoldingSummaryParams<'_>,
+    ) -> Result<DerivedHoldingSummaryResponse, Error> {
+        let access_token = self.client.ensure_token(&self.login_name).await?;
+        let (endpoint, api_version, http_client) = {
+            let state = self.client.state.read().unwrap();
+            (
+                // endpoint
+                format!("{}/{}", state.api_endpoint, "derived/holdingSummary"),
+                state.api_version.clone(),
+                state.http_client.clone(),
+            )
+        };
+
+        let res = http_client
+            .get(endpoint)
+            .header("Api-Version", api_version)
+            .header(header::AUTHORIZATION, format!("Bearer {access_token}"))
+            .query(&[
+                ("accountIds", params.account_ids.map(|e| e.join(","))),
+                (
+                    "classificationType",
+                    params.classification_type.map(|s| s.to_string()),
+                ),
+                ("include", params.include.map(|s| s.to_string())),
+            ])
+            .send()
+            .await?;
+
+        if res.status().is_success() {
+            Ok(res.json().await?)
+        } else {
+            Err(Error::Api(res.json().await?))
+        }
+    }
+
+    /// `group_by` is either `CATEGORY_TYPE` or `CATEGORY`.
+    pub async fn get_transaction_summary(
+        &mut self,
+        group_by: &str,
+        params: DerivedTransactionSummaryParams<'_>,
+    ) -> Result<DerivedTransactionSummaryResponse, Error> {
+        let access_token = self.client.ensure_token(&self.login_name).await?;
+        let (endpoint, api_version, http_client) = {
+            let state = self.client.state.read().unwrap();
+            (
+                // endpoint
+                format!("{}/{}", state.api_endpoint, "derived/transactionSummary"),
+                state.api_version.clone(),
+                state.http_client.clone(),
+            )
+        };
+
+        let res = http_client
+            .get(endpoint)
+            .header("Api-Version", api_version)
+            .header(header::AUTHORIZATION, format!("Bearer {access_token}"))
+            .query(&[
+                ("groupBy", Some(group_by.to_string())),
+                ("accountIds", params.account_ids.map(|e| e.join(","))),
+                ("categoryIds", params.category_ids.map(|e| e.join(","))),
+                ("categoryType", params.category_type.map(|s| s.to_string())),
+                ("fromDate", params.from_date.map(|s| s.to_string())),
+                ("toDate", params.to_date.map(|s| s.to_string())),
+                ("interval", params.interval.map(|s| s.to_string())),
+                ("include", params.include.map(|s| s.to_string())),
+            ])
+            .send()
+            .await?;
+
+        if res.status().is_success() {
+            Ok(res.json().await?)
+        } else {
+            Err(Error::Api(res.json().await?))
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DerivedNetworthParams<'a> {
+    pub account_ids: Option<&'a [&'a str]>,
+    pub container: Option<&'a str>,
+    pub from_date: Option<&'a str>,
+    pub to_date: Option<&'a str>,
+    /// One of `D`, `W` or `M` for daily, weekly or monthly points.
+    pub interval: Option<&'a str>,
+    /// Pass `details` to get the account balances behind each point.
+    pub include: Option<&'a str>,
+    pub skip: Option<u32>,
+    pub top: Option<u32>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct DerivedNetworthResponse {
+    pub networth: Option<Vec<DerivedNetworth>>,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DerivedHoldingSummaryParams<'a> {
+    pub account_ids: Option<&'a [&'a str]>,
+    /// One of the classification types returned by
+    /// `Holding::get_asset_classification_list`, e.g. `ASSET_CLASS`,
+    /// `COUNTRY`, `SECTOR` or `STYLE`.
+    pub classification_type: Option<&'a str>,
+    /// Pass `details` to get the holdings behind each classification.
+    pub include: Option<&'a str>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DerivedHoldingSummaryResponse {
+    pub holding_summary: Option<Vec<DerivedHoldingSummary>>,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DerivedTransactionSummaryParams<'a> {
+    pub account_ids: Option<&'a [&'a str]>,
+    pub category_ids: Option<&'a [&'a str]>,
+    pub category_type: Option<&'a str>,
+    pub from_date: Option<&'a str>,
+    pub to_date: Option<&'a str>,
+    /// One of `D`, `W`, `M` or `Y` for the period each detail covers.
+    pub interval: Option<&'a str>,
+    /// Pass `details` to get the totals for each period.
+    pub include: Option<&'a str>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DerivedTransactionSummaryResponse {
+    pub transaction_summary: Option<Vec<DerivedTransactionSummary>>,
+}
@@ -0,0 +1,373 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use futures::{stream, Stream, StreamExt};
+use reqwest::header;
+use serde::{Deserialize, Serialize};
+
+use crate::{error::Error, models::Document as DocumentModel, Client};
+
+#[derive(Debug, Clone)]
+pub struct Document {
+    login_name: String,
+    client: Client,
+}
+
+impl Document {
+    pub fn new(client: Client, login_name: String) -> Self {
+        Document { login_name, client }
+    }
+
+    pub async fn get_documents(
+        &mut self,
+        params: DocumentParams<'_>,
+    ) -> Result<DocumentResponse, Error> {
+        let access_token = self.client.ensure_token(&self.login_name).await?;
+        let (endpoint, api_version, http_client) = {
+            let state = self.client.state.read().unwrap();
+            (
+                // endpoint
+                format!("{}/{}", state.api_endpoint, "documents"),
+                state.api_version.clone(),
+                state.http_client.clone(),
+            )
+        };
+
+        let res = http_client
+            .get(endpoint)
+            .header("Api-Version", api_version)
+            .header(header::AUTHORIZATION, format!("Bearer {access_token}"))
+            .query(&[
+                ("Keyword", params.keyword.map(|s| s.to_string())),
+                ("accountId", params.account_id.map(|s| s.to_string())),
+                ("docType", params.doc_type.map(|s| s.to_string())),
+                ("fromDate", params.from_date.map(|s| s.to_string())),
+                ("toDate", params.to_date.map(|s| s.to_string())),
+            ])
+            .send()
+            .await?;
+
+        if res.status().is_success() {
+            Ok(res.json().await?)
+        } else {
+            Err(Error::Api(res.json().await?))
+        }
+    }
+
+    /// Downloads a document. The API returns the document base64 encoded
+    /// inside a JSON body, which is decoded as it arrives so the stream yields
+    /// chunks of the raw document (usually a PDF) without ever holding all of
+    /// it in memory.
+    pub async fn download(
+        &mut self,
+        document_id: String,
+    ) -> Result<impl Stream<Item = Result<Vec<u8>, Error>>, Error> {
+        let access_token = self.client.ensure_token(&self.login_name).await?;
+        let (endpoint, api_version, http_client) = {
+            let state = self.client.state.read().unwrap();
+            (
+                // endpoint
+                format!("{}/{}/{}", state.api_endpoint, "documents", document_id),
+                state.api_version.clone(),
+                state.http_client.clone(),
+            )
+        };
+
+        let res = http_client
+            .get(endpoint)
+            .header("Api-Version", api_version)
+            .header(header::AUTHORIZATION, format!("Bearer {access_token}"))
+            .send()
+            .await?;
+
+        if !res.status().is_success() {
+            return Err(Error::Api(res.json().await?));
+        }
+
+        let body = res.bytes_stream();
+        Ok(stream::try_unfold(
+            (Box::pin(body), DocContentDecoder::default()),
+            |(mut body, mut decoder)| async move {
+                while let Some(chunk) = body.next().await {
+                    let decoded = decoder.feed(&chunk?)?;
+                    if !decoded.is_empty() {
+                        return Ok(Some((decoded, (body, decoder))));
+                    }
+                }
+
+                decoder.finish()?;
+                Ok(None)
+            },
+        ))
+    }
+
+    pub async fn delete(&mut self, document_id: String) -> Result<(), Error> {
+        let access_token = self.client.ensure_token(&self.login_name).await?;
+        let (endpoint, api_version, http_client) = {
+            let state = self.client.state.read().unwrap();
+            (
+                // endpoint
+                format!("{}/{}/{}", state.api_endpoint, "documents", document_id),
+                state.api_version.clone(),
+                state.http_client.clone(),
+            )
+        };
+
+        let res = http_client
+            .delete(endpoint)
+            .header("Api-Version", api_version)
+            .header(header::AUTHORIZATION, format!("Bearer {access_token}"))
+            .send()
+            .await?;
+
+        if res.status().is_success() {
+            Ok(())
+        } else {
+            Err(Error::Api(res.json().await?))
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DocumentParams<'a> {
+    pub keyword: Option<&'a str>,
+    pub account_id: Option<&'a str>,
+    /// One of `STMT`, `TAX` or `EBILL`.
+    pub doc_type: Option<&'a str>,
+    pub from_date: Option<&'a str>,
+    pub to_date: Option<&'a str>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct DocumentResponse {
+    pub document: Option<Vec<DocumentModel>>,
+}
+
+const DOC_CONTENT_KEY: &[u8] = b"\"docContent\"";
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+enum DecoderState {
+    #[default]
+    Key,
+    Colon,
+    Quote,
+    Content,
+    Escape,
+    // a `\uXXXX` escape, with the hex digits read so far
+    Unicode {
+        digits: u8,
+        value: u32,
+    },
+    Done,
+}
+
+/// Pulls the `docContent` string out of a document download response and
+/// base64 decodes it, one chunk of the response body at a time.
+#[derive(Debug, Default)]
+struct DocContentDecoder {
+    state: DecoderState,
+    // how much of `DOC_CONTENT_KEY` has been matched so far
+    matched: usize,
+    // base64 characters that don't yet make up a full 4 character group
+    pending: Vec<u8>,
+}
+
+impl DocContentDecoder {
+    fn feed(&mut self, chunk: &[u8]) -> Result<Vec<u8>, Error> {
+        for &b in chunk {
+            match self.state {
+                DecoderState::Key => {
+                    // the only other quote in the key is the closing one, so
+                    // on a mismatch the match can only restart at the byte
+                    // just read, and only if it is an opening quote
+                    if b == DOC_CONTENT_KEY[self.matched] {
+                        self.matched += 1;
+                    } else if b == DOC_CONTENT_KEY[0] {
+                        self.matched = 1;
+                    } else {
+                        self.matched = 0;
+                    }
+
+                    if self.matched == DOC_CONTENT_KEY.len() {
+                        self.matched = 0;
+                        self.state = DecoderState::Colon;
+                    }
+                }
+                DecoderState::Colon => match b {
+                    b':' => self.state = DecoderState::Quote,
+                    b if b.is_ascii_whitespace() => {}
+                    _ => self.state = DecoderState::Key,
+                },
+                DecoderState::Quote => match b {
+                    b'"' => self.state = DecoderState::Content,
+                    b if b.is_ascii_whitespace() => {}
+                    _ => self.state = DecoderState::Key,
+                },
+                DecoderState::Content => match b {
+                    b'\\' => self.state = DecoderState::Escape,
+                    b'"' => {
+                        self.state = DecoderState::Done;
+                        break;
+                    }
+                    _ => self.pending.push(b),
+                },
+                DecoderState::Escape => {
+                    self.state = DecoderState::Content;
+                    match b {
+                        b'/' => self.pending.push(b),
+                        // line breaks in MIME style base64
+                        b'n' | b'r' => {}
+                        b'u' => {
+                            self.state = DecoderState::Unicode {
+                                digits: 0,
+                                value: 0,
+                            }
+                        }
+                        _ => {
+                            return Err(Error::InvalidDocumentContent(format!(
+                                "unexpected escape `\\{}`",
+                                b.escape_ascii()
+                            )))
+                        }
+                    }
+                }
+                DecoderState::Unicode { digits, value } => {
+                    let digit = char::from(b).to_digit(16).ok_or_else(|| {
+                        Error::InvalidDocumentContent(format!(
+                            "invalid hex digit `{}` in `\\u` escape",
+                            b.escape_ascii()
+                        ))
+                    })?;
+                    let value = value << 4 | digit;
+
+                    if digits < 3 {
+                        self.state = DecoderState::Unicode {
+                            digits: digits + 1,
+                            value,
+                        };
+                        continue;
+                    }
+
+                    self.state = DecoderState::Content;
+                    match char::from_u32(value) {
+                        Some(c) if c.is_ascii_whitespace() => {}
+                        Some(c) if c.is_ascii() => self.pending.push(c as u8),
+                        _ => {
+                            return Err(Error::InvalidDocumentContent(format!(
+                                "unexpected character `\\u{value:04x}`"
+                            )))
+                        }
+                    }
+                }
+                DecoderState::Done => break,
+            }
+        }
+
+        let len = if self.state == DecoderState::Done {
+            self.pending.len()
+        } else {
+            self.pending.len() / 4 * 4
+        };
+        let decoded = STANDARD.decode(&self.pending[..len])?;
+        self.pending.drain(..len);
+
+        Ok(decoded)
+    }
+
+    fn finish(&self) -> Result<(), Error> {
+        if self.state == DecoderState::Done {
+            Ok(())
+        } else {
+            Err(Error::NoDocumentContent)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // what `JVBERi0xLjcK` decodes to
+    const CONTENT: &[u8] = b"%PDF-1.7\n";
+
+    fn decode(body: &[u8], chunk_size: usize) -> Result<Vec<u8>, Error> {
+        let mut decoder = DocContentDecoder::default();
+        let mut decoded = Vec::new();
+        for chunk in body.chunks(chunk_size) {
+            decoded.extend(decoder.feed(chunk)?);
+        }
+        decoder.finish()?;
+
+        Ok(decoded)
+    }
+
+    #[test]
+    fn test_decode() {
+        let body = br#"{"document":[{"id":"DOC1","docContent":"JVBERi0xLjcK"}]}"#;
+        assert_eq!(decode(body, body.len()).unwrap(), CONTENT);
+    }
+
+    #[test]
+    fn test_decode_key_split_across_chunks() {
+        let body = br#"{"docTitle":"docContent","docContent":"JVBERi0xLjcK"}"#;
+        // split the second `docContent`, the key, in two
+        let split = body.windows(4).rposition(|w| w == b"Cont").unwrap() + 2;
+        let (first, second) = body.split_at(split);
+
+        let mut decoder = DocContentDecoder::default();
+        let mut decoded = decoder.feed(first).unwrap();
+        decoded.extend(decoder.feed(second).unwrap());
+        decoder.finish().unwrap();
+        assert_eq!(decoded, CONTENT);
+    }
+
+    #[test]
+    fn test_decode_one_byte_chunks() {
+        let body = br#"{"id":"DOC1","docContent":"JVBERi0xLjcK","status":"DONE"}"#;
+        assert_eq!(decode(body, 1).unwrap(), CONTENT);
+    }
+
+    #[test]
+    fn test_decode_whitespace_around_colon() {
+        let body = b"{\"docContent\" \n:\t \"JVBERi0xLjcK\"}";
+        assert_eq!(decode(body, 3).unwrap(), CONTENT);
+    }
+
+    #[test]
+    fn test_decode_escapes() {
+        // `\/` for `/`, `\r\n` line breaks and `\u003d` for `=`
+        let body = br#"{"docContent":"Pz8\/Pz8\r\n\u002fP34\u003d"}"#;
+        assert_eq!(decode(body, 2).unwrap(), b"???????~");
+    }
+
+    #[test]
+    fn test_decode_unexpected_escape() {
+        let body = br#"{"docContent":"JVBE\tRi0xLjcK"}"#;
+        assert!(matches!(
+            decode(body, 4),
+            Err(Error::InvalidDocumentContent(_))
+        ));
+
+        let body = br#"{"docContent":"JVBE\u00e9Ri0xLjcK"}"#;
+        assert!(matches!(
+            decode(body, 4),
+            Err(Error::InvalidDocumentContent(_))
+        ));
+    }
+
+    #[test]
+    fn test_decode_missing_content() {
+        let body = br#"{"document":[{"id":"DOC1","docTitle":"docContent"}]}"#;
+        assert!(matches!(decode(body, 8), Err(Error::NoDocumentContent)));
+
+        let body = br#"{"docContent":"JVBERi0x"#;
+        assert!(matches!(decode(body, 8), Err(Error::NoDocumentContent)));
+    }
+
+    #[test]
+    fn test_decode_invalid_base64() {
+        let body = br#"{"docContent":"JVBE*i0xLjcK"}"#;
+        assert!(matches!(decode(body, 4), Err(Error::Decode(_))));
+
+        let body = br#"{"docContent":"JVBERi0xLjc"}"#;
+        assert!(matches!(decode(body, 4), Err(Error::Decode(_))));
+    }
+}
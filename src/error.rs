@@ -14,6 +14,12 @@ pub enum Error {
     #[error("HTTP error: {0}")]
     Http(#[from] reqwest::Error),
 
+    #[error("Could not parse JSON: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
     #[error("Unknown Yodlee API error.")]
     Unknown,
 
@@ -26,8 +32,32 @@ pub enum Error {
     #[error("No valid access token is available for the user.")]
     NoToken,
 
+    #[error("No FastLink URL is configured for this environment.")]
+    NoFastLinkUrl,
+
+    #[error("The response did not contain the provider account.")]
+    NoProviderAccount,
+
+    #[error("Provider account refresh did not finish after {0} polls.")]
+    RefreshTimedOut(u32),
+
     #[error("Could not cleanly close the client.")]
     Close,
+
+    #[error("Could not decode document content: {0}")]
+    Decode(#[from] base64::DecodeError),
+
+    #[error("The response did not contain any document content.")]
+    NoDocumentContent,
+
+    #[error("Could not read document content: {0}")]
+    InvalidDocumentContent(String),
+
+    #[error("The sync cursor is older than the data extracts lookback allows.")]
+    CursorExpired,
+
+    #[error("Sync sink failed: {0}")]
+    Sink(Box<dyn std::error::Error + Send + Sync>),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
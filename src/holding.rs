@@ -0,0 +1,185 @@
+use reqwest::header;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::Error,
+    models::{AssetClassificationList, Holding as HoldingModel, SecurityHolding},
+    Client,
+};
+
+#[derive(Debug, Clone)]
+pub struct Holding {
+    login_name: String,
+    client: Client,
+}
+
+impl Holding {
+    pub fn new(client: Client, login_name: String) -> Self {
+        Holding { login_name, client }
+    }
+
+    pub async fn get_holdings(
+        &mut self,
+        params: HoldingParams<'_>,
+    ) -> Result<HoldingResponse, Error> {
+        let access_token = self.client.ensure_token(&self.login_name).await?;
+        let (endpoint, api_version, http_client) = {
+            let state = self.client.state.read().unwrap();
+            (
+                // endpoint
+                format!("{}/{}", state.api_endpoint, "holdings"),
+                state.api_version.clone(),
+                state.http_client.clone(),
+            )
+        };
+
+        let res = http_client
+            .get(endpoint)
+            .header("Api-Version", api_version)
+            .header(header::AUTHORIZATION, format!("Bearer {access_token}"))
+            .query(&[
+                ("accountId", params.account_id.map(|s| s.to_string())),
+                (
+                    "providerAccountId",
+                    params.provider_account_id.map(|s| s.to_string()),
+                ),
+                ("include", params.include.map(|s| s.to_string())),
+                (
+                    "assetClassification.classificationType",
+                    params.classification_type.map(|s| s.to_string()),
+                ),
+                (
+                    "classificationValue",
+                    params.classification_value.map(|s| s.to_string()),
+                ),
+            ])
+            .send()
+            .await?;
+
+        if res.status().is_success() {
+            Ok(res.json().await?)
+        } else {
+            Err(Error::Api(res.json().await?))
+        }
+    }
+
+    pub async fn get_securities(
+        &mut self,
+        holding_ids: &[&str],
+    ) -> Result<SecurityResponse, Error> {
+        let access_token = self.client.ensure_token(&self.login_name).await?;
+        let (endpoint, api_version, http_client) = {
+            let state = self.client.state.read().unwrap();
+            (
+                // endpoint
+                format!("{}/{}", state.api_endpoint, "holdings/securities"),
+                state.api_version.clone(),
+                state.http_client.clone(),
+            )
+        };
+
+        let res = http_client
+            .get(endpoint)
+            .header("Api-Version", api_version)
+            .header(header::AUTHORIZATION, format!("Bearer {access_token}"))
+            .query(&[("holdingId", holding_ids.join(","))])
+            .send()
+            .await?;
+
+        if res.status().is_success() {
+            Ok(res.json().await?)
+        } else {
+            Err(Error::Api(res.json().await?))
+        }
+    }
+
+    pub async fn get_holding_type_list(&mut self) -> Result<HoldingTypeListResponse, Error> {
+        let access_token = self.client.ensure_token(&self.login_name).await?;
+        let (endpoint, api_version, http_client) = {
+            let state = self.client.state.read().unwrap();
+            (
+                // endpoint
+                format!("{}/{}", state.api_endpoint, "holdings/holdingTypeList"),
+                state.api_version.clone(),
+                state.http_client.clone(),
+            )
+        };
+
+        let res = http_client
+            .get(endpoint)
+            .header("Api-Version", api_version)
+            .header(header::AUTHORIZATION, format!("Bearer {access_token}"))
+            .send()
+            .await?;
+
+        if res.status().is_success() {
+            Ok(res.json().await?)
+        } else {
+            Err(Error::Api(res.json().await?))
+        }
+    }
+
+    pub async fn get_asset_classification_list(
+        &mut self,
+    ) -> Result<AssetClassificationListResponse, Error> {
+        let access_token = self.client.ensure_token(&self.login_name).await?;
+        let (endpoint, api_version, http_client) = {
+            let state = self.client.state.read().unwrap();
+            (
+                // endpoint
+                format!(
+                    "{}/{}",
+                    state.api_endpoint, "holdings/assetClassificationList"
+                ),
+                state.api_version.clone(),
+                state.http_client.clone(),
+            )
+        };
+
+        let res = http_client
+            .get(endpoint)
+            .header("Api-Version", api_version)
+            .header(header::AUTHORIZATION, format!("Bearer {access_token}"))
+            .send()
+            .await?;
+
+        if res.status().is_success() {
+            Ok(res.json().await?)
+        } else {
+            Err(Error::Api(res.json().await?))
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct HoldingParams<'a> {
+    pub account_id: Option<&'a str>,
+    pub provider_account_id: Option<&'a str>,
+    /// Pass `assetClassification` to have each holding's asset classification
+    /// included in the response.
+    pub include: Option<&'a str>,
+    pub classification_type: Option<&'a str>,
+    pub classification_value: Option<&'a str>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct HoldingResponse {
+    pub holding: Option<Vec<HoldingModel>>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct SecurityResponse {
+    pub holding: Option<Vec<SecurityHolding>>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HoldingTypeListResponse {
+    pub holding_type: Option<Vec<String>>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetClassificationListResponse {
+    pub asset_classification_list: Option<Vec<AssetClassificationList>>,
+}
@@ -3,18 +3,46 @@
 use std::sync::{Arc, RwLock};
 
 use account::Account;
+use categorization_rule::CategorizationRule;
+use config::Config;
+use data_extracts::DataExtracts;
+use derived::Derived;
+use document::Document;
 use error::Error;
+use holding::Holding;
 use models::UserRegistration;
+use provider::Provider;
+use provider_account::ProviderAccount;
 use reqwest::{header, Client as HttpClient};
 use serde::{Deserialize, Serialize};
+use statement::Statement;
+use sync::SyncEngine;
 use token_manager::TokenManager;
+use transaction::Transaction;
 use user::{User, UserDetailsResponse};
+use verification::Verification;
 
 pub mod account;
+pub mod categorization_rule;
+pub mod config;
+pub mod data_extracts;
+pub mod derived;
+pub mod document;
 pub mod error;
+pub mod holding;
 pub mod models;
+mod paginator;
+pub mod provider;
+pub mod provider_account;
+pub mod statement;
+pub mod sync;
 mod token_manager;
+pub mod transaction;
 pub mod user;
+pub mod verification;
+pub mod webhook;
+#[cfg(feature = "webhook-server")]
+pub mod webhook_server;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum ClientState {
@@ -29,6 +57,7 @@ pub(crate) struct State {
     admin_login_name: String,
     client_id: String,
     client_secret: String,
+    fastlink_url: Option<String>,
     http_client: HttpClient,
     state: ClientState,
 }
@@ -53,6 +82,7 @@ impl Client {
             admin_login_name,
             client_id,
             client_secret,
+            fastlink_url: None,
             http_client: HttpClient::new(),
             state: ClientState::Closed,
         }));
@@ -69,6 +99,14 @@ impl Client {
         self
     }
 
+    /// Sets the FastLink URL to launch FastLink with. Each development and
+    /// production environment has its own, so this must be set outside of the
+    /// sandbox.
+    pub fn with_fastlink_url(self, fastlink_url: String) -> Self {
+        self.state.write().unwrap().fastlink_url = Some(fastlink_url);
+        self
+    }
+
     pub fn is_open(&self) -> bool {
         self.state.read().unwrap().state == ClientState::Open
     }
@@ -81,6 +119,54 @@ impl Client {
         Account::new(self.clone(), login_name)
     }
 
+    pub fn transaction(&self, login_name: String) -> Transaction {
+        Transaction::new(self.clone(), login_name)
+    }
+
+    pub fn categorization_rule(&self, login_name: String) -> CategorizationRule {
+        CategorizationRule::new(self.clone(), login_name)
+    }
+
+    pub fn holding(&self, login_name: String) -> Holding {
+        Holding::new(self.clone(), login_name)
+    }
+
+    pub fn derived(&self, login_name: String) -> Derived {
+        Derived::new(self.clone(), login_name)
+    }
+
+    pub fn provider(&self, login_name: String) -> Provider {
+        Provider::new(self.clone(), login_name)
+    }
+
+    pub fn provider_account(&self, login_name: String) -> ProviderAccount {
+        ProviderAccount::new(self.clone(), login_name)
+    }
+
+    pub fn statement(&self, login_name: String) -> Statement {
+        Statement::new(self.clone(), login_name)
+    }
+
+    pub fn document(&self, login_name: String) -> Document {
+        Document::new(self.clone(), login_name)
+    }
+
+    pub fn verification(&self, login_name: String) -> Verification {
+        Verification::new(self.clone(), login_name)
+    }
+
+    pub fn config(&self) -> Config {
+        Config::new(self.clone())
+    }
+
+    pub fn data_extracts(&self) -> DataExtracts {
+        DataExtracts::new(self.clone())
+    }
+
+    pub fn sync_engine(&self) -> SyncEngine {
+        SyncEngine::new(self.clone())
+    }
+
     pub async fn open(&mut self) -> Result<(), Error> {
         // if we are already in open state, don't do nothing
         if self.is_open() {
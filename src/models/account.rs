@@ -29,6 +29,7 @@ pub struct Account {
     pub available_credit: Option<Money>,
     pub current_balance: Option<Money>,
     pub is_manual: Option<bool>,
+    pub is_deleted: Option<bool>,
     pub profile: Option<AccountProfile>,
     pub escrow_balance: Option<Money>,
     pub next_level: Option<String>,
@@ -116,4 +117,7 @@ pub struct Account {
     pub last_employee_contribution_date: Option<String>,
     pub last_payment: Option<Money>,
     pub recurring_payment: Option<Money>,
+    /// Only returned by the latest balances call, where it tracks the real
+    /// time balance fetch for this account.
+    pub refresh_status: Option<String>,
 }
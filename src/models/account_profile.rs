@@ -1,13 +1,14 @@
 use serde::{Deserialize, Serialize};
 
 use super::{
-    account_address::AccountAddress, email::Email, identifier::Identifier,
+    account_address::AccountAddress, email::Email, identifier::Identifier, name::Name,
     phone_number::PhoneNumber,
 };
 
 #[derive(Debug, Default, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AccountProfile {
+    pub name: Option<Name>,
     pub identifier: Option<Vec<Identifier>>,
     pub address: Option<Vec<AccountAddress>>,
     pub phone_number: Option<Vec<PhoneNumber>>,
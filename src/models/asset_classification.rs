@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetClassification {
+    pub allocation: Option<f64>,
+    pub classification_type: Option<String>,
+    pub classification_value: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetClassificationList {
+    pub classification_type: Option<String>,
+    pub classification_value: Option<Vec<String>>,
+}
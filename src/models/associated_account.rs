@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+use super::Money;
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssociatedAccount {
+    pub id: Option<i64>,
+    pub account_name: Option<String>,
+    pub account_number: Option<String>,
+    pub account_type: Option<String>,
+    pub account_status: Option<String>,
+    #[serde(rename = "CONTAINER")]
+    pub container: Option<String>,
+    pub balance: Option<Money>,
+    pub provider_account_id: Option<i64>,
+    pub provider_name: Option<String>,
+}
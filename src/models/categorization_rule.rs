@@ -0,0 +1,143 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CategorizationRule {
+    pub user_defined_rule_id: Option<i64>,
+    pub category_level_id: Option<i64>,
+    pub transaction_categorisation_id: Option<i64>,
+    pub mem_id: Option<i64>,
+    pub priority: Option<i64>,
+    pub rule_clauses: Option<Vec<CategorizationRuleClause>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CategorizationRuleClause {
+    pub rule_clause_id: Option<i64>,
+    pub user_defined_rule_id: Option<i64>,
+    #[serde(flatten)]
+    pub clause: RuleClause,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CategorizationRuleRequest {
+    pub category_id: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i64>,
+    pub rule_clause: Vec<RuleClause>,
+}
+
+/// A single condition of a categorization rule. A transaction matches a rule
+/// when it matches every clause of the rule.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(from = "RawRuleClause", into = "RawRuleClause")]
+pub enum RuleClause {
+    DescriptionContains(String),
+    DescriptionEquals(String),
+    AmountEquals(f64),
+    AmountLessThan(f64),
+    AmountLessThanOrEqual(f64),
+    AmountGreaterThan(f64),
+    AmountGreaterThanOrEqual(f64),
+    /// A clause this crate does not know how to type, kept as returned by the
+    /// API so that it can be sent back unchanged.
+    Other {
+        field: String,
+        operation: String,
+        value: Value,
+    },
+}
+
+impl RuleClause {
+    /// Clauses matching amounts between `min` and `max`, both inclusive.
+    pub fn amount_range(min: f64, max: f64) -> [RuleClause; 2] {
+        [
+            RuleClause::AmountGreaterThanOrEqual(min),
+            RuleClause::AmountLessThanOrEqual(max),
+        ]
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+struct RawRuleClause {
+    field: String,
+    operation: String,
+    // rules are created with `value` but come back with `fieldValue`
+    #[serde(alias = "fieldValue")]
+    value: Value,
+}
+
+impl From<RawRuleClause> for RuleClause {
+    fn from(raw: RawRuleClause) -> Self {
+        // amounts sometimes come back as strings
+        let amount = match &raw.value {
+            Value::Number(n) => n.as_f64(),
+            Value::String(s) => s.parse().ok(),
+            _ => None,
+        };
+
+        match (
+            raw.field.as_str(),
+            raw.operation.as_str(),
+            &raw.value,
+            amount,
+        ) {
+            ("description", "stringContains", Value::String(s), _) => {
+                RuleClause::DescriptionContains(s.clone())
+            }
+            ("description", "stringEquals", Value::String(s), _) => {
+                RuleClause::DescriptionEquals(s.clone())
+            }
+            ("amount", "numberEquals", _, Some(a)) => RuleClause::AmountEquals(a),
+            ("amount", "numberLessThan", _, Some(a)) => RuleClause::AmountLessThan(a),
+            ("amount", "numberLessThanEquals", _, Some(a)) => RuleClause::AmountLessThanOrEqual(a),
+            ("amount", "numberGreaterThan", _, Some(a)) => RuleClause::AmountGreaterThan(a),
+            ("amount", "numberGreaterThanEquals", _, Some(a)) => {
+                RuleClause::AmountGreaterThanOrEqual(a)
+            }
+            _ => RuleClause::Other {
+                field: raw.field,
+                operation: raw.operation,
+                value: raw.value,
+            },
+        }
+    }
+}
+
+impl From<RuleClause> for RawRuleClause {
+    fn from(clause: RuleClause) -> Self {
+        let (field, operation, value) = match clause {
+            RuleClause::DescriptionContains(s) => ("description", "stringContains", Value::from(s)),
+            RuleClause::DescriptionEquals(s) => ("description", "stringEquals", Value::from(s)),
+            RuleClause::AmountEquals(a) => ("amount", "numberEquals", Value::from(a)),
+            RuleClause::AmountLessThan(a) => ("amount", "numberLessThan", Value::from(a)),
+            RuleClause::AmountLessThanOrEqual(a) => {
+                ("amount", "numberLessThanEquals", Value::from(a))
+            }
+            RuleClause::AmountGreaterThan(a) => ("amount", "numberGreaterThan", Value::from(a)),
+            RuleClause::AmountGreaterThanOrEqual(a) => {
+                ("amount", "numberGreaterThanEquals", Value::from(a))
+            }
+            RuleClause::Other {
+                field,
+                operation,
+                value,
+            } => {
+                return RawRuleClause {
+                    field,
+                    operation,
+                    value,
+                }
+            }
+        };
+
+        RawRuleClause {
+            field: field.to_string(),
+            operation: operation.to_string(),
+            value,
+        }
+    }
+}
@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Coordinates {
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+}
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct Count {
+    #[serde(rename = "TOTAL")]
+    pub total: Option<TotalCount>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct TotalCount {
+    pub count: Option<i64>,
+}
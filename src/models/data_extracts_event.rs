@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DataExtractsEventData {
+    pub user_count: Option<i64>,
+    pub from_date: Option<String>,
+    pub to_date: Option<String>,
+    pub user_data: Option<Vec<DataExtractsEventUserData>>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DataExtractsEventUserData {
+    pub user: Option<DataExtractsUser>,
+    pub links: Option<Vec<DataExtractsEventLink>>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DataExtractsUser {
+    pub login_name: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DataExtractsEventLink {
+    pub method_type: Option<String>,
+    pub rel: Option<String>,
+    pub href: Option<String>,
+}
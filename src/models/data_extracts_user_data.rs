@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+use super::{Account, DataExtractsUser, Holding, ProviderAccount, Transaction};
+
+/// Everything that changed for a user within a data extracts window. Records
+/// that were deleted come back with `is_deleted` set.
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DataExtractsUserData {
+    pub user: Option<DataExtractsUser>,
+    pub account: Option<Vec<Account>>,
+    pub transaction: Option<Vec<Transaction>>,
+    pub holding: Option<Vec<Holding>>,
+    pub provider_account: Option<Vec<ProviderAccount>>,
+}
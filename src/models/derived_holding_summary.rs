@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+use super::{Holding, Money};
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DerivedHoldingSummary {
+    pub classification_type: Option<String>,
+    pub classification_value: Option<String>,
+    pub value: Option<Money>,
+    pub holding: Option<Vec<Holding>>,
+    pub account: Option<Vec<DerivedHoldingsAccount>>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DerivedHoldingsAccount {
+    pub id: Option<i64>,
+    pub value: Option<Money>,
+}
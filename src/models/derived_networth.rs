@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+use super::{HistoricalBalance, Money};
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DerivedNetworth {
+    pub date: Option<String>,
+    pub asset: Option<Money>,
+    pub liability: Option<Money>,
+    pub networth: Option<Money>,
+    /// The per account balances that make up this point. Only returned when
+    /// `include=details` is requested.
+    pub historical_balances: Option<Vec<HistoricalBalance>>,
+}
@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+
+use super::Money;
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DerivedTransactionSummary {
+    pub category_type: Option<String>,
+    pub category_summary: Option<Vec<DerivedCategorySummary>>,
+    pub credit_total: Option<Money>,
+    pub debit_total: Option<Money>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DerivedCategorySummary {
+    pub id: Option<i64>,
+    pub category_name: Option<String>,
+    pub credit_total: Option<Money>,
+    pub debit_total: Option<Money>,
+    pub details: Option<Vec<DerivedCategorySummaryDetails>>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DerivedCategorySummaryDetails {
+    pub date: Option<String>,
+    pub credit_total: Option<Money>,
+    pub debit_total: Option<Money>,
+}
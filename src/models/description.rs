@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Description {
+    pub security: Option<String>,
+    pub original: Option<String>,
+    pub simple: Option<String>,
+    pub consumer: Option<String>,
+}
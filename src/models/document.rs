@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Document {
+    pub id: Option<String>,
+    #[serde(rename = "accountID")]
+    pub account_id: Option<i64>,
+    pub name: Option<String>,
+    pub doc_type: Option<String>,
+    pub form_type: Option<String>,
+    pub source_id: Option<String>,
+    pub status: Option<String>,
+    pub last_updated: Option<String>,
+}
@@ -0,0 +1,36 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum EventName {
+    Refresh,
+    DataUpdates,
+    AutoRefreshUpdates,
+    LatestBalanceUpdates,
+}
+
+impl EventName {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EventName::Refresh => "REFRESH",
+            EventName::DataUpdates => "DATA_UPDATES",
+            EventName::AutoRefreshUpdates => "AUTO_REFRESH_UPDATES",
+            EventName::LatestBalanceUpdates => "LATEST_BALANCE_UPDATES",
+        }
+    }
+}
+
+impl fmt::Display for EventName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventSubscription {
+    pub name: Option<String>,
+    pub callback_url: Option<String>,
+}
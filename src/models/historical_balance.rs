@@ -3,7 +3,9 @@ use serde::{Deserialize, Serialize};
 use super::Money;
 
 #[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct HistoricalBalance {
+    pub account_id: Option<i64>,
     pub date: Option<String>,
     pub is_asset: Option<bool>,
     pub balance: Option<Money>,
@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+
+use super::{AssetClassification, Money};
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Holding {
+    pub id: Option<i64>,
+    pub account_id: Option<i64>,
+    pub provider_account_id: Option<i64>,
+    pub symbol: Option<String>,
+    pub cusip_number: Option<String>,
+    pub isin: Option<String>,
+    pub sedol: Option<String>,
+    pub description: Option<String>,
+    pub enriched_description: Option<String>,
+    pub holding_type: Option<String>,
+    pub security_type: Option<String>,
+    pub security_style: Option<String>,
+    pub quantity: Option<f64>,
+    pub price: Option<Money>,
+    pub value: Option<Money>,
+    pub cost_basis: Option<Money>,
+    pub accrued_interest: Option<Money>,
+    pub accrued_income: Option<Money>,
+    pub spread: Option<Money>,
+    pub strike_price: Option<Money>,
+    pub unvested_value: Option<Money>,
+    pub vested_value: Option<Money>,
+    pub unvested_quantity: Option<f64>,
+    pub vested_quantity: Option<f64>,
+    pub vested_shares_exercisable: Option<f64>,
+    pub contract_quantity: Option<f64>,
+    pub coupon_rate: Option<f64>,
+    pub interest_rate: Option<f64>,
+    pub is_short: Option<bool>,
+    pub is_deleted: Option<bool>,
+    pub option_type: Option<String>,
+    pub term: Option<String>,
+    pub match_status: Option<String>,
+    pub maturity_date: Option<String>,
+    pub expiration_date: Option<String>,
+    pub grant_date: Option<String>,
+    pub created_date: Option<String>,
+    pub last_updated: Option<String>,
+    pub asset_classification: Option<Vec<AssetClassification>>,
+}
@@ -0,0 +1,82 @@
+use serde::{Deserialize, Serialize};
+
+// Login forms are returned by the API and then sent back filled in, so empty
+// fields are left out when serializing rather than sent as nulls.
+
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LoginForm {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    #[serde(rename = "forgetPasswordURL", skip_serializing_if = "Option::is_none")]
+    pub forget_password_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub form_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mfa_info_title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mfa_info_text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mfa_timeout: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub help: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub login_help: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub row: Option<Vec<Row>>,
+}
+
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Row {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub form: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub field_row_choice: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub field: Option<Vec<Field>>,
+}
+
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Field {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub _type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_optional: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value_editable: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_length: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prefix: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suffix: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub option: Option<Vec<FieldOption>>,
+}
+
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldOption {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub option_value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_selected: Option<bool>,
+}
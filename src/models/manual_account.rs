@@ -0,0 +1,75 @@
+use serde::{Deserialize, Serialize};
+
+use super::{AccountAddress, Money};
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ManualAccount {
+    pub account_name: String,
+    pub account_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_number: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nickname: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memo: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub balance: Option<Money>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount_due: Option<Money>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub frequency: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_in_net_worth: Option<bool>,
+    /// Real estate accounts only. `SYSTEM` has Yodlee value the property at
+    /// `address`, `MANUAL` uses `home_value` instead.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub valuation_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub home_value: Option<Money>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<AccountAddress>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub container: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_number: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nickname: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memo: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub balance: Option<Money>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount_due: Option<Money>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub frequency: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_in_net_worth: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_ebill_enrolled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub home_value: Option<Money>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<AccountAddress>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub linked_account_ids: Option<Vec<i64>>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreatedAccount {
+    pub id: Option<i64>,
+}
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+use super::{AccountAddress, Contact, Coordinates};
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Merchant {
+    pub website: Option<String>,
+    pub address: Option<AccountAddress>,
+    pub contact: Option<Contact>,
+    pub category_label: Option<Vec<String>>,
+    pub coordinates: Option<Coordinates>,
+    pub name: Option<String>,
+    pub id: Option<String>,
+    pub source: Option<String>,
+    #[serde(rename = "logoURL")]
+    pub logo_url: Option<String>,
+}
@@ -4,23 +4,50 @@ mod account_dataset;
 mod account_history;
 mod account_holder;
 mod account_profile;
+mod asset_classification;
+mod associated_account;
 mod auto_refresh;
 mod bank_transfer_code;
+mod categorization_rule;
+mod contact;
+mod coordinates;
+mod count;
 mod coverage;
 mod coverage_amount;
+mod data_extracts_event;
+mod data_extracts_user_data;
+mod derived_holding_summary;
+mod derived_networth;
+mod derived_transaction_summary;
+mod description;
+mod detail_category;
+mod document;
 mod email;
+mod event;
 mod full_account_number_list;
 mod historical_balance;
+mod holding;
 mod identifier;
 mod loan_payoff_details;
+mod login_form;
+mod manual_account;
+mod merchant;
 mod money;
 mod name;
 mod payment_bank_transfer_code;
 mod payment_identifier;
 mod payment_profile;
 mod phone_number;
+mod provider;
+mod provider_account;
 mod reward_balance;
+mod security;
+mod statement;
+mod transaction;
+mod transaction_category;
+mod transaction_update;
 mod user;
+mod verification;
 
 pub use account::*;
 pub use account_address::*;
@@ -28,20 +55,47 @@ pub use account_dataset::*;
 pub use account_history::*;
 pub use account_holder::*;
 pub use account_profile::*;
+pub use asset_classification::*;
+pub use associated_account::*;
 pub use auto_refresh::*;
 pub use bank_transfer_code::*;
+pub use categorization_rule::*;
+pub use contact::*;
+pub use coordinates::*;
+pub use count::*;
 pub use coverage::*;
 pub use coverage_amount::*;
+pub use data_extracts_event::*;
+pub use data_extracts_user_data::*;
+pub use derived_holding_summary::*;
+pub use derived_networth::*;
+pub use derived_transaction_summary::*;
+pub use description::*;
+pub use detail_category::*;
+pub use document::*;
 pub use email::*;
+pub use event::*;
 pub use full_account_number_list::*;
 pub use historical_balance::*;
+pub use holding::*;
 pub use identifier::*;
 pub use loan_payoff_details::*;
+pub use login_form::*;
+pub use manual_account::*;
+pub use merchant::*;
 pub use money::*;
 pub use name::*;
 pub use payment_bank_transfer_code::*;
 pub use payment_identifier::*;
 pub use payment_profile::*;
 pub use phone_number::*;
+pub use provider::*;
+pub use provider_account::*;
 pub use reward_balance::*;
+pub use security::*;
+pub use statement::*;
+pub use transaction::*;
+pub use transaction_category::*;
+pub use transaction_update::*;
 pub use user::*;
+pub use verification::*;
@@ -0,0 +1,63 @@
+use serde::{Deserialize, Serialize};
+
+use super::LoginForm;
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Provider {
+    pub id: Option<i64>,
+    pub name: Option<String>,
+    pub login_url: Option<String>,
+    pub base_url: Option<String>,
+    pub favicon: Option<String>,
+    pub logo: Option<String>,
+    pub status: Option<String>,
+    #[serde(rename = "PRIORITY")]
+    pub priority: Option<String>,
+    pub auth_type: Option<String>,
+    pub auth_parameter: Option<Vec<String>>,
+    pub is_consent_required: Option<bool>,
+    pub is_auto_refresh_enabled: Option<bool>,
+    pub is_added_by_user: Option<String>,
+    #[serde(rename = "languageISOCode")]
+    pub language_iso_code: Option<String>,
+    #[serde(rename = "primaryLanguageISOCode")]
+    pub primary_language_iso_code: Option<String>,
+    #[serde(rename = "countryISOCode")]
+    pub country_iso_code: Option<String>,
+    pub forget_password_url: Option<String>,
+    pub help: Option<String>,
+    pub login_help: Option<String>,
+    pub last_modified: Option<String>,
+    pub associated_provider_ids: Option<Vec<i64>>,
+    pub capability: Option<Vec<Capability>>,
+    pub dataset: Option<Vec<ProvidersDataset>>,
+    pub login_form: Option<Vec<LoginForm>>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Capability {
+    pub name: Option<String>,
+    pub container: Option<Vec<String>>,
+}
+
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProvidersDataset {
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attribute: Option<Vec<Attribute>>,
+}
+
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Attribute {
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub container: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to_date: Option<String>,
+}
@@ -0,0 +1,64 @@
+use serde::{Deserialize, Serialize};
+
+use super::{AccountDataset, AccountProfile, Field, LoginForm, ProvidersDataset};
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProviderAccount {
+    pub id: Option<i64>,
+    pub provider_id: Option<i64>,
+    pub request_id: Option<String>,
+    pub status: Option<String>,
+    pub auth_type: Option<String>,
+    pub aggregation_source: Option<String>,
+    pub consent_id: Option<i64>,
+    pub is_manual: Option<bool>,
+    pub is_deleted: Option<bool>,
+    #[serde(rename = "isRealTimeMFA")]
+    pub is_real_time_mfa: Option<bool>,
+    pub oauth_migration_status: Option<String>,
+    pub source_provider_account_ids: Option<Vec<i64>>,
+    pub destination_provider_account_id: Option<i64>,
+    pub created_date: Option<String>,
+    pub last_updated: Option<String>,
+    pub preferences: Option<ProviderAccountPreferences>,
+    pub dataset: Option<Vec<AccountDataset>>,
+    pub login_form: Option<Vec<LoginForm>>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProviderAccountProfile {
+    pub id: Option<i64>,
+    pub profile: Option<Vec<AccountProfile>>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProviderAccountPreferences {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_data_extracts_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_auto_refresh_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub linked_provider_account_id: Option<i64>,
+}
+
+/// The body of a provider account update. Fill in `login_form` to update
+/// credentials, or `field` to answer MFA questions for a refresh in progress.
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProviderAccountRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub login_form: Option<LoginForm>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub field: Option<Vec<Field>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preferences: Option<ProviderAccountPreferences>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub consent_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dataset_name: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dataset: Option<Vec<ProvidersDataset>>,
+}
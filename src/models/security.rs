@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Security {
+    pub id: Option<i64>,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub ticker: Option<String>,
+    pub cusip: Option<String>,
+    pub isin: Option<String>,
+    pub sedol: Option<String>,
+    pub exchange: Option<String>,
+    pub trade_currency_code: Option<String>,
+    pub income_currency: Option<String>,
+    pub country_of_issue: Option<String>,
+    pub gics_sector: Option<String>,
+    pub sector: Option<String>,
+    pub sub_sector: Option<String>,
+    pub style: Option<String>,
+    pub share_class: Option<String>,
+    pub fund_family: Option<String>,
+    #[serde(rename = "type")]
+    pub _type: Option<String>,
+    pub sub_type: Option<String>,
+    pub frequency: Option<String>,
+    pub issue_type_multiplier: Option<f64>,
+    pub shares_outstanding: Option<f64>,
+    pub first_coupon_date: Option<String>,
+    pub call_date: Option<String>,
+    pub call_price: Option<f64>,
+    pub is_environmental_good: Option<bool>,
+    pub is_synthetic_security: Option<bool>,
+    pub is_dummy_security: Option<bool>,
+    pub state_taxable: Option<bool>,
+    pub cdsc_fund_flag: Option<bool>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SecurityHolding {
+    /// The ID of the holding this security belongs to.
+    pub id: Option<i64>,
+    pub security: Option<Security>,
+}
@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+
+use super::Money;
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Statement {
+    pub id: Option<i64>,
+    pub account_id: Option<i64>,
+    pub statement_date: Option<String>,
+    pub billing_period_start: Option<String>,
+    pub billing_period_end: Option<String>,
+    pub due_date: Option<String>,
+    pub is_latest: Option<bool>,
+    pub last_updated: Option<String>,
+    pub amount_due: Option<Money>,
+    pub minimum_payment: Option<Money>,
+    pub new_charges: Option<Money>,
+    pub cash_advance: Option<Money>,
+    pub last_payment_date: Option<String>,
+    pub last_payment_amount: Option<Money>,
+    pub principal_amount: Option<Money>,
+    pub interest_amount: Option<Money>,
+    pub loan_balance: Option<Money>,
+    pub interest_rate: Option<f64>,
+    pub apr: Option<f64>,
+    pub cash_apr: Option<f64>,
+}
@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+
+use super::{Description, Merchant, Money};
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Transaction {
+    #[serde(rename = "CONTAINER")]
+    pub container: Option<String>,
+    pub id: Option<i64>,
+    pub amount: Option<Money>,
+    pub interest: Option<Money>,
+    pub principal: Option<Money>,
+    pub commission: Option<Money>,
+    pub price: Option<Money>,
+    pub running_balance: Option<Money>,
+    pub base_type: Option<String>,
+    pub category_type: Option<String>,
+    pub category_id: Option<i64>,
+    pub category: Option<String>,
+    pub category_source: Option<String>,
+    pub high_level_category_id: Option<i64>,
+    pub detail_category_id: Option<i64>,
+    pub parent_category_id: Option<i64>,
+    pub created_date: Option<String>,
+    pub last_updated: Option<String>,
+    pub description: Option<Description>,
+    pub memo: Option<String>,
+    pub date: Option<String>,
+    pub transaction_date: Option<String>,
+    pub post_date: Option<String>,
+    pub settle_date: Option<String>,
+    pub status: Option<String>,
+    pub account_id: Option<i64>,
+    pub is_manual: Option<bool>,
+    pub is_deleted: Option<bool>,
+    pub is_physical: Option<bool>,
+    pub merchant: Option<Merchant>,
+    pub source_id: Option<String>,
+    pub source_type: Option<String>,
+    pub source_apca_number: Option<String>,
+    pub source_bank_name: Option<String>,
+    pub source_merchant_category_code: Option<String>,
+    pub source_transaction_type: Option<String>,
+    #[serde(rename = "type")]
+    pub _type: Option<String>,
+    pub sub_type: Option<String>,
+    pub check_number: Option<String>,
+    pub quantity: Option<f64>,
+    pub symbol: Option<String>,
+    pub cusip_number: Option<String>,
+    pub holding_description: Option<String>,
+    pub valoren: Option<String>,
+    pub sedol: Option<String>,
+    pub isin: Option<String>,
+}
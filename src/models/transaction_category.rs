@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+
+use super::DetailCategory;
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionCategory {
+    pub id: Option<i64>,
+    pub category: Option<String>,
+    pub classification: Option<String>,
+    pub source: Option<String>,
+    #[serde(rename = "type")]
+    pub _type: Option<String>,
+    pub default_category_name: Option<String>,
+    pub high_level_category_id: Option<i64>,
+    pub high_level_category_name: Option<String>,
+    pub default_high_level_category_name: Option<String>,
+    pub detail_category: Option<Vec<DetailCategory>>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionCategoryRequest {
+    pub category_name: String,
+    pub parent_category_id: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateCategoryRequest {
+    pub id: i64,
+    /// Either `SYSTEM` or `USER`. Renaming a `SYSTEM` category only changes
+    /// its name for this user.
+    pub source: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub high_level_category_name: Option<String>,
+}
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+use super::Description;
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionUpdate {
+    /// Either `SYSTEM` or `USER`, depending on where `category_id` came from.
+    pub category_source: String,
+    pub container: String,
+    pub category_id: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<Description>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memo: Option<String>,
+}
@@ -0,0 +1,102 @@
+use serde::{Deserialize, Serialize};
+
+use super::{AccountHolder, BankTransferCode, FullAccountNumberList, Money};
+
+// Verifications are both sent to and returned by the API, so empty fields are
+// left out when serializing.
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Verification {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verification_id: Option<i64>,
+    /// Either `CHALLENGE_DEPOSIT` or `MATCHING`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verification_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verification_status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verification_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider_account_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account: Option<VerificationAccount>,
+    /// The challenge deposit amounts the user saw, when verifying.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction: Option<Vec<VerificationTransaction>>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerificationAccount {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_number: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bank_transfer_code: Option<BankTransferCode>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerificationTransaction {
+    pub amount: Money,
+    pub base_type: String,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerifiedAccount {
+    pub account_id: Option<i64>,
+    pub account_name: Option<String>,
+    pub account_number: Option<String>,
+    pub display_account_number: Option<String>,
+    pub account_type: Option<String>,
+    #[serde(rename = "CONTAINER")]
+    pub container: Option<String>,
+    pub classification: Option<String>,
+    pub provider_account_id: Option<i64>,
+    pub provider_id: Option<String>,
+    pub provider_name: Option<String>,
+    pub is_selected: Option<bool>,
+    pub verification_status: Option<String>,
+    pub balance: Option<Money>,
+    pub current_balance: Option<Money>,
+    pub available_balance: Option<Money>,
+    pub holder: Option<Vec<AccountHolder>>,
+    pub full_account_number_list: Option<FullAccountNumberList>,
+    pub bank_transfer_code: Option<Vec<BankTransferCode>>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerifyAccountRequest {
+    pub container: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<i64>,
+    pub transaction_criteria: Vec<VerifyTransactionCriteria>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerifyTransactionCriteria {
+    pub amount: f64,
+    pub date: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keyword: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_variance: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verified_days_range: Option<i64>,
+    /// Only set in responses, to `COMPLETE`, `PARTIAL` or `NONE`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub matched: Option<String>,
+}
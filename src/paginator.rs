@@ -0,0 +1,101 @@
+use std::future::Future;
+
+use futures::{stream, Stream, TryStreamExt};
+
+use crate::error::Error;
+
+/// The largest page size the Yodlee API accepts for `top`.
+pub(crate) const MAX_PAGE_SIZE: u32 = 500;
+
+/// Walks an endpoint that is paged with `skip`/`top` until a short page comes
+/// back. `fetch` is called with the `skip` and `top` to request and returns the
+/// items on that page along with the number of records the API counted
+/// against `top` (which is not always the number of items, e.g. historical
+/// balances are paged per balance but grouped per account).
+pub(crate) fn paginate<T, F, Fut>(
+    skip: u32,
+    top: u32,
+    fetch: F,
+) -> impl Stream<Item = Result<T, Error>>
+where
+    F: FnMut(u32, u32) -> Fut,
+    Fut: Future<Output = Result<(Vec<T>, usize), Error>>,
+{
+    let top = top.clamp(1, MAX_PAGE_SIZE);
+
+    stream::try_unfold((fetch, Some(skip)), move |(mut fetch, skip)| async move {
+        let skip = match skip {
+            Some(skip) => skip,
+            None => return Ok::<_, Error>(None),
+        };
+
+        let (items, count) = fetch(skip, top).await?;
+        let next = if count < top as usize {
+            None
+        } else {
+            Some(skip + top)
+        };
+
+        Ok(Some((
+            stream::iter(items.into_iter().map(Ok)),
+            (fetch, next),
+        )))
+    })
+    .try_flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use futures::TryStreamExt;
+
+    use super::*;
+
+    // Pages through `total` numbered items, recording the (skip, top) of
+    // every call.
+    async fn collect(skip: u32, top: u32, total: u32) -> (Vec<u32>, Vec<(u32, u32)>) {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let items = paginate(skip, top, {
+            let calls = calls.clone();
+            move |skip, top| {
+                calls.lock().unwrap().push((skip, top));
+                let items = (skip..total.min(skip + top)).collect::<Vec<_>>();
+                async move {
+                    let count = items.len();
+                    Ok((items, count))
+                }
+            }
+        })
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+
+        let calls = calls.lock().unwrap().clone();
+        (items, calls)
+    }
+
+    #[tokio::test]
+    async fn test_short_last_page() {
+        let (items, calls) = collect(0, 10, 25).await;
+        assert_eq!(items, (0..25).collect::<Vec<_>>());
+        assert_eq!(calls, vec![(0, 10), (10, 10), (20, 10)]);
+    }
+
+    #[tokio::test]
+    async fn test_full_last_page() {
+        let (items, calls) = collect(5, 10, 25).await;
+        assert_eq!(items, (5..25).collect::<Vec<_>>());
+        assert_eq!(calls, vec![(5, 10), (15, 10), (25, 10)]);
+    }
+
+    #[tokio::test]
+    async fn test_top_clamped() {
+        let (items, calls) = collect(0, 1000, 700).await;
+        assert_eq!(items, (0..700).collect::<Vec<_>>());
+        assert_eq!(calls, vec![(0, MAX_PAGE_SIZE), (500, MAX_PAGE_SIZE)]);
+
+        let (_, calls) = collect(0, 0, 2).await;
+        assert_eq!(calls, vec![(0, 1), (1, 1), (2, 1)]);
+    }
+}
@@ -0,0 +1,157 @@
+use reqwest::header;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::Error,
+    models::{Count, Provider as ProviderModel},
+    Client,
+};
+
+#[derive(Debug, Clone)]
+pub struct Provider {
+    login_name: String,
+    client: Client,
+}
+
+impl Provider {
+    pub fn new(client: Client, login_name: String) -> Self {
+        Provider { login_name, client }
+    }
+
+    pub async fn get_providers(
+        &mut self,
+        params: ProviderParams<'_>,
+    ) -> Result<ProviderResponse, Error> {
+        let access_token = self.client.ensure_token(&self.login_name).await?;
+        let (endpoint, api_version, http_client) = {
+            let state = self.client.state.read().unwrap();
+            (
+                // endpoint
+                format!("{}/{}", state.api_endpoint, "providers"),
+                state.api_version.clone(),
+                state.http_client.clone(),
+            )
+        };
+
+        let res = http_client
+            .get(endpoint)
+            .header("Api-Version", api_version)
+            .header(header::AUTHORIZATION, format!("Bearer {access_token}"))
+            .query(&[
+                ("name", params.name.map(|s| s.to_string())),
+                ("priority", params.priority.map(|s| s.to_string())),
+                (
+                    "dataset$filter",
+                    params.dataset_filter.map(|s| s.to_string()),
+                ),
+                (
+                    "additionalDataSet",
+                    params.additional_dataset.map(|s| s.to_string()),
+                ),
+                ("capability", params.capability.map(|s| s.to_string())),
+                ("skip", params.skip.map(|s| s.to_string())),
+                ("top", params.top.map(|s| s.to_string())),
+            ])
+            .send()
+            .await?;
+
+        if res.status().is_success() {
+            Ok(res.json().await?)
+        } else {
+            Err(Error::Api(res.json().await?))
+        }
+    }
+
+    pub async fn get_provider(&mut self, provider_id: String) -> Result<ProviderResponse, Error> {
+        let access_token = self.client.ensure_token(&self.login_name).await?;
+        let (endpoint, api_version, http_client) = {
+            let state = self.client.state.read().unwrap();
+            (
+                // endpoint
+                format!("{}/{}/{}", state.api_endpoint, "providers", provider_id),
+                state.api_version.clone(),
+                state.http_client.clone(),
+            )
+        };
+
+        let res = http_client
+            .get(endpoint)
+            .header("Api-Version", api_version)
+            .header(header::AUTHORIZATION, format!("Bearer {access_token}"))
+            .send()
+            .await?;
+
+        if res.status().is_success() {
+            Ok(res.json().await?)
+        } else {
+            Err(Error::Api(res.json().await?))
+        }
+    }
+
+    /// Counts the providers matching `params`. `skip` and `top` are ignored.
+    pub async fn get_providers_count(
+        &mut self,
+        params: ProviderParams<'_>,
+    ) -> Result<ProviderCountResponse, Error> {
+        let access_token = self.client.ensure_token(&self.login_name).await?;
+        let (endpoint, api_version, http_client) = {
+            let state = self.client.state.read().unwrap();
+            (
+                // endpoint
+                format!("{}/{}", state.api_endpoint, "providers/count"),
+                state.api_version.clone(),
+                state.http_client.clone(),
+            )
+        };
+
+        let res = http_client
+            .get(endpoint)
+            .header("Api-Version", api_version)
+            .header(header::AUTHORIZATION, format!("Bearer {access_token}"))
+            .query(&[
+                ("name", params.name.map(|s| s.to_string())),
+                ("priority", params.priority.map(|s| s.to_string())),
+                (
+                    "dataset$filter",
+                    params.dataset_filter.map(|s| s.to_string()),
+                ),
+                (
+                    "additionalDataSet",
+                    params.additional_dataset.map(|s| s.to_string()),
+                ),
+                ("capability", params.capability.map(|s| s.to_string())),
+            ])
+            .send()
+            .await?;
+
+        if res.status().is_success() {
+            Ok(res.json().await?)
+        } else {
+            Err(Error::Api(res.json().await?))
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ProviderParams<'a> {
+    pub name: Option<&'a str>,
+    /// One of `popular`, `suggested`, `cobrand` or `all`.
+    pub priority: Option<&'a str>,
+    /// Filters on datasets and their attributes, e.g.
+    /// `(BASIC_AGG_DATA.ACCOUNT_DETAILS AND BASIC_AGG_DATA.HOLDINGS)`.
+    pub dataset_filter: Option<&'a str>,
+    pub additional_dataset: Option<&'a str>,
+    pub capability: Option<&'a str>,
+    pub skip: Option<u32>,
+    pub top: Option<u32>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct ProviderResponse {
+    pub provider: Option<Vec<ProviderModel>>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct ProviderCountResponse {
+    pub provider: Option<Count>,
+}
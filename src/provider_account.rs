@@ -0,0 +1,541 @@
+use std::{future::Future, time::Duration};
+
+use futures::{stream, Stream, TryStreamExt};
+use reqwest::header;
+use serde::{Deserialize, Serialize};
+use tokio::time::sleep;
+
+use crate::{
+    error::Error,
+    models::{
+        ProviderAccount as ProviderAccountModel, ProviderAccountPreferences,
+        ProviderAccountProfile, ProviderAccountRequest,
+    },
+    Client,
+};
+
+#[derive(Debug, Clone)]
+pub struct ProviderAccount {
+    login_name: String,
+    client: Client,
+}
+
+impl ProviderAccount {
+    pub fn new(client: Client, login_name: String) -> Self {
+        ProviderAccount { login_name, client }
+    }
+
+    pub async fn get_provider_accounts(
+        &mut self,
+        params: ProviderAccountParams<'_>,
+    ) -> Result<ProviderAccountResponse, Error> {
+        let access_token = self.client.ensure_token(&self.login_name).await?;
+        let (endpoint, api_version, http_client) = {
+            let state = self.client.state.read().unwrap();
+            (
+                // endpoint
+                format!("{}/{}", state.api_endpoint, "providerAccounts"),
+                state.api_version.clone(),
+                state.http_client.clone(),
+            )
+        };
+
+        let res = http_client
+            .get(endpoint)
+            .header("Api-Version", api_version)
+            .header(header::AUTHORIZATION, format!("Bearer {access_token}"))
+            .query(&[
+                ("include", params.include.map(|s| s.to_string())),
+                ("providerIds", params.provider_ids.map(|e| e.join(","))),
+            ])
+            .send()
+            .await?;
+
+        if res.status().is_success() {
+            Ok(res.json().await?)
+        } else {
+            Err(Error::Api(res.json().await?))
+        }
+    }
+
+    /// `include` can be any of `credentials`, `questions` or `preferences`,
+    /// comma separated.
+    pub async fn get_provider_account(
+        &mut self,
+        provider_account_id: String,
+        include: Option<&str>,
+    ) -> Result<ProviderAccountResponse, Error> {
+        let access_token = self.client.ensure_token(&self.login_name).await?;
+        let (endpoint, api_version, http_client) = {
+            let state = self.client.state.read().unwrap();
+            (
+                // endpoint
+                format!(
+                    "{}/{}/{}",
+                    state.api_endpoint, "providerAccounts", provider_account_id
+                ),
+                state.api_version.clone(),
+                state.http_client.clone(),
+            )
+        };
+
+        let res = http_client
+            .get(endpoint)
+            .header("Api-Version", api_version)
+            .header(header::AUTHORIZATION, format!("Bearer {access_token}"))
+            .query(&[("include", include)])
+            .send()
+            .await?;
+
+        if res.status().is_success() {
+            Ok(res.json().await?)
+        } else {
+            Err(Error::Api(res.json().await?))
+        }
+    }
+
+    pub async fn update(
+        &mut self,
+        provider_account_ids: &[&str],
+        req: ProviderAccountRequest,
+    ) -> Result<ProviderAccountResponse, Error> {
+        let access_token = self.client.ensure_token(&self.login_name).await?;
+        let (endpoint, api_version, http_client) = {
+            let state = self.client.state.read().unwrap();
+            (
+                // endpoint
+                format!("{}/{}", state.api_endpoint, "providerAccounts"),
+                state.api_version.clone(),
+                state.http_client.clone(),
+            )
+        };
+
+        let res = http_client
+            .put(endpoint)
+            .header("Api-Version", api_version)
+            .header(header::AUTHORIZATION, format!("Bearer {access_token}"))
+            .query(&[("providerAccountIds", provider_account_ids.join(","))])
+            .json(&req)
+            .send()
+            .await?;
+
+        if res.status().is_success() {
+            Ok(res.json().await?)
+        } else {
+            Err(Error::Api(res.json().await?))
+        }
+    }
+
+    /// Returns the profile (holder name, address, email etc.) the provider
+    /// holds for each of the given provider accounts.
+    pub async fn get_profiles(
+        &mut self,
+        provider_account_ids: &[&str],
+    ) -> Result<ProviderAccountProfileResponse, Error> {
+        let access_token = self.client.ensure_token(&self.login_name).await?;
+        let (endpoint, api_version, http_client) = {
+            let state = self.client.state.read().unwrap();
+            (
+                // endpoint
+                format!("{}/{}", state.api_endpoint, "providerAccounts/profile"),
+                state.api_version.clone(),
+                state.http_client.clone(),
+            )
+        };
+
+        let res = http_client
+            .get(endpoint)
+            .header("Api-Version", api_version)
+            .header(header::AUTHORIZATION, format!("Bearer {access_token}"))
+            .query(&[("providerAccountId", provider_account_ids.join(","))])
+            .send()
+            .await?;
+
+        if res.status().is_success() {
+            Ok(res.json().await?)
+        } else {
+            Err(Error::Api(res.json().await?))
+        }
+    }
+
+    pub async fn update_preferences(
+        &mut self,
+        provider_account_id: String,
+        preferences: ProviderAccountPreferences,
+    ) -> Result<(), Error> {
+        let access_token = self.client.ensure_token(&self.login_name).await?;
+        let (endpoint, api_version, http_client) = {
+            let state = self.client.state.read().unwrap();
+            (
+                // endpoint
+                format!(
+                    "{}/{}/{}/{}",
+                    state.api_endpoint, "providerAccounts", provider_account_id, "preferences"
+                ),
+                state.api_version.clone(),
+                state.http_client.clone(),
+            )
+        };
+
+        let req = ProviderAccountPreferencesRequest { preferences };
+        let res = http_client
+            .put(endpoint)
+            .header("Api-Version", api_version)
+            .header(header::AUTHORIZATION, format!("Bearer {access_token}"))
+            .json(&req)
+            .send()
+            .await?;
+
+        if res.status().is_success() {
+            Ok(())
+        } else {
+            Err(Error::Api(res.json().await?))
+        }
+    }
+
+    /// Kicks off a refresh of the provider account. The returned
+    /// `RefreshStatus` can be used to wait for the refresh to finish.
+    pub async fn refresh(&mut self, provider_account_id: String) -> Result<RefreshStatus, Error> {
+        self.update(&[&provider_account_id], Default::default())
+            .await?;
+
+        Ok(RefreshStatus {
+            provider_account: self.clone(),
+            provider_account_id,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            max_polls: DEFAULT_MAX_POLLS,
+        })
+    }
+
+    pub async fn delete(&mut self, provider_account_id: String) -> Result<(), Error> {
+        let access_token = self.client.ensure_token(&self.login_name).await?;
+        let (endpoint, api_version, http_client) = {
+            let state = self.client.state.read().unwrap();
+            (
+                // endpoint
+                format!(
+                    "{}/{}/{}",
+                    state.api_endpoint, "providerAccounts", provider_account_id
+                ),
+                state.api_version.clone(),
+                state.http_client.clone(),
+            )
+        };
+
+        let res = http_client
+            .delete(endpoint)
+            .header("Api-Version", api_version)
+            .header(header::AUTHORIZATION, format!("Bearer {access_token}"))
+            .send()
+            .await?;
+
+        if res.status().is_success() {
+            Ok(())
+        } else {
+            Err(Error::Api(res.json().await?))
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ProviderAccountParams<'a> {
+    pub include: Option<&'a str>,
+    pub provider_ids: Option<&'a [&'a str]>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProviderAccountResponse {
+    pub provider_account: Option<Vec<ProviderAccountModel>>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProviderAccountProfileResponse {
+    pub provider_account: Option<Vec<ProviderAccountProfile>>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct ProviderAccountPreferencesRequest {
+    preferences: ProviderAccountPreferences,
+}
+
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(5);
+// ten minutes at the default poll interval
+const DEFAULT_MAX_POLLS: u32 = 120;
+
+/// Provider account statuses of a refresh that is still making progress.
+/// Every other status ends the refresh.
+pub const IN_PROGRESS_REFRESH_STATUSES: &[&str] =
+    &["LOGIN_IN_PROGRESS", "IN_PROGRESS", "MIGRATION_IN_PROGRESS"];
+
+/// Dataset `additional_status`es of a dataset that is still being retrieved.
+pub const IN_PROGRESS_DATASET_STATUSES: &[&str] = &[
+    "LOGIN_IN_PROGRESS",
+    "DATA_RETRIEVAL_IN_PROGRESS",
+    "ACCT_SUMMARY_RECEIVED",
+];
+
+/// Tracks a refresh started with `ProviderAccount::refresh` by polling the
+/// provider account until `RefreshStatus::is_terminal` says it is done.
+/// Polling gives up with `Error::RefreshTimedOut` after `max_polls` polls,
+/// 120 by default.
+#[derive(Debug)]
+pub struct RefreshStatus {
+    provider_account: ProviderAccount,
+    provider_account_id: String,
+    poll_interval: Duration,
+    max_polls: u32,
+}
+
+impl RefreshStatus {
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    pub fn with_max_polls(mut self, max_polls: u32) -> Self {
+        self.max_polls = max_polls;
+        self
+    }
+
+    pub fn provider_account_id(&self) -> &str {
+        &self.provider_account_id
+    }
+
+    /// Whether the refresh of `provider_account` is done. That is when its
+    /// status is known and not one of `IN_PROGRESS_REFRESH_STATUSES`, and
+    /// none of its datasets is still being retrieved: a dataset is, when its
+    /// `additional_status` is one of `IN_PROGRESS_DATASET_STATUSES`, or when
+    /// it has no `additional_status` yet and its `update_eligibility` is
+    /// `DISALLOW_UPDATE`.
+    pub fn is_terminal(provider_account: &ProviderAccountModel) -> bool {
+        let status_done = provider_account
+            .status
+            .as_deref()
+            .map(|status| !IN_PROGRESS_REFRESH_STATUSES.contains(&status))
+            .unwrap_or(false);
+
+        let datasets_done =
+            provider_account.dataset.iter().flatten().all(|dataset| {
+                match dataset.additional_status.as_deref() {
+                    Some(status) => !IN_PROGRESS_DATASET_STATUSES.contains(&status),
+                    None => dataset.update_eligibility.as_deref() != Some("DISALLOW_UPDATE"),
+                }
+            });
+
+        status_done && datasets_done
+    }
+
+    /// Streams a snapshot of the provider account on every poll. The stream
+    /// ends after the first snapshot that `is_terminal`, or with
+    /// `Error::RefreshTimedOut` once `max_polls` snapshots have come back
+    /// without one. Each snapshot's `dataset` carries the `additional_status`
+    /// and `update_eligibility` of the individual datasets being refreshed.
+    pub fn into_stream(self) -> impl Stream<Item = Result<ProviderAccountModel, Error>> {
+        let RefreshStatus {
+            provider_account,
+            provider_account_id,
+            poll_interval,
+            max_polls,
+        } = self;
+
+        poll_until_terminal(poll_interval, max_polls, move || {
+            let mut provider_account = provider_account.clone();
+            let provider_account_id = provider_account_id.clone();
+            async move {
+                provider_account
+                    .get_provider_account(provider_account_id, None)
+                    .await?
+                    .provider_account
+                    .and_then(|mut p| p.pop())
+                    .ok_or(Error::NoProviderAccount)
+            }
+        })
+    }
+
+    /// Polls until the refresh finishes and returns the final snapshot of the
+    /// provider account.
+    pub async fn wait(self) -> Result<ProviderAccountModel, Error> {
+        self.into_stream()
+            .try_fold(None, |_, provider_account| async move {
+                Ok(Some(provider_account))
+            })
+            .await?
+            .ok_or(Error::NoProviderAccount)
+    }
+}
+
+fn poll_until_terminal<F, Fut>(
+    poll_interval: Duration,
+    max_polls: u32,
+    fetch: F,
+) -> impl Stream<Item = Result<ProviderAccountModel, Error>>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<ProviderAccountModel, Error>>,
+{
+    stream::try_unfold(Some((fetch, 0)), move |state| async move {
+        let (mut fetch, polls) = match state {
+            Some(state) => state,
+            None => return Ok::<_, Error>(None),
+        };
+
+        if polls >= max_polls {
+            return Err(Error::RefreshTimedOut(max_polls));
+        }
+        if polls > 0 {
+            sleep(poll_interval).await;
+        }
+
+        let provider_account = fetch().await?;
+        let next = if RefreshStatus::is_terminal(&provider_account) {
+            None
+        } else {
+            Some((fetch, polls + 1))
+        };
+
+        Ok(Some((provider_account, next)))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+
+    use futures::{StreamExt, TryStreamExt};
+
+    use super::*;
+    use crate::models::AccountDataset;
+
+    fn snapshot(status: &str) -> ProviderAccountModel {
+        ProviderAccountModel {
+            status: Some(status.to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn statuses(snapshots: &[ProviderAccountModel]) -> Vec<&str> {
+        snapshots
+            .iter()
+            .map(|p| p.status.as_deref().unwrap())
+            .collect()
+    }
+
+    // polls hand out the given snapshots in order, then fail like an empty
+    // response would
+    fn poll(
+        max_polls: u32,
+        snapshots: &[&str],
+    ) -> impl Stream<Item = Result<ProviderAccountModel, Error>> {
+        let mut snapshots = snapshots
+            .iter()
+            .map(|status| snapshot(status))
+            .collect::<VecDeque<_>>();
+        poll_until_terminal(Duration::ZERO, max_polls, move || {
+            let next = snapshots.pop_front().ok_or(Error::NoProviderAccount);
+            async move { next }
+        })
+    }
+
+    fn with_dataset(
+        mut provider_account: ProviderAccountModel,
+        additional_status: Option<&str>,
+        update_eligibility: &str,
+    ) -> ProviderAccountModel {
+        provider_account.dataset = Some(vec![AccountDataset {
+            name: Some("BASIC_AGG_DATA".to_string()),
+            additional_status: additional_status.map(str::to_string),
+            update_eligibility: Some(update_eligibility.to_string()),
+            ..Default::default()
+        }]);
+        provider_account
+    }
+
+    #[test]
+    fn test_is_terminal() {
+        for status in IN_PROGRESS_REFRESH_STATUSES {
+            assert!(!RefreshStatus::is_terminal(&snapshot(status)));
+        }
+        assert!(!RefreshStatus::is_terminal(&Default::default()));
+
+        for status in [
+            "SUCCESS",
+            "PARTIAL_SUCCESS",
+            "FAILED",
+            "USER_INPUT_REQUIRED",
+        ] {
+            assert!(RefreshStatus::is_terminal(&snapshot(status)));
+        }
+        // statuses that aren't listed anywhere end the refresh as well
+        assert!(RefreshStatus::is_terminal(&snapshot(
+            "DATA_RETRIEVAL_FAILED"
+        )));
+    }
+
+    #[test]
+    fn test_is_terminal_datasets() {
+        let done = with_dataset(
+            snapshot("SUCCESS"),
+            Some("AVAILABLE_DATA_RETRIEVED"),
+            "ALLOW_UPDATE",
+        );
+        assert!(RefreshStatus::is_terminal(&done));
+
+        let failed = with_dataset(
+            snapshot("FAILED"),
+            Some("INCORRECT_CREDENTIALS"),
+            "ALLOW_UPDATE_WITH_CREDENTIALS",
+        );
+        assert!(RefreshStatus::is_terminal(&failed));
+
+        let retrieving = with_dataset(
+            snapshot("SUCCESS"),
+            Some("DATA_RETRIEVAL_IN_PROGRESS"),
+            "DISALLOW_UPDATE",
+        );
+        assert!(!RefreshStatus::is_terminal(&retrieving));
+
+        let summary_only = with_dataset(
+            snapshot("PARTIAL_SUCCESS"),
+            Some("ACCT_SUMMARY_RECEIVED"),
+            "DISALLOW_UPDATE",
+        );
+        assert!(!RefreshStatus::is_terminal(&summary_only));
+
+        let not_started = with_dataset(snapshot("SUCCESS"), None, "DISALLOW_UPDATE");
+        assert!(!RefreshStatus::is_terminal(&not_started));
+    }
+
+    #[tokio::test]
+    async fn test_stops_after_terminal_snapshot() {
+        let snapshots = poll(
+            10,
+            &["IN_PROGRESS", "IN_PROGRESS", "SUCCESS", "IN_PROGRESS"],
+        )
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+        assert_eq!(
+            statuses(&snapshots),
+            vec!["IN_PROGRESS", "IN_PROGRESS", "SUCCESS"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_times_out_after_max_polls() {
+        let res = poll(2, &["IN_PROGRESS", "IN_PROGRESS", "SUCCESS"])
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(res.len(), 3);
+        assert!(res[..2].iter().all(Result::is_ok));
+        assert!(matches!(res[2], Err(Error::RefreshTimedOut(2))));
+    }
+
+    #[tokio::test]
+    async fn test_missing_provider_account() {
+        let res = poll(10, &["IN_PROGRESS"]).collect::<Vec<_>>().await;
+        assert_eq!(res.len(), 2);
+        assert!(matches!(res[1], Err(Error::NoProviderAccount)));
+    }
+}
@@ -0,0 +1,67 @@
+use reqwest::header;
+use serde::{Deserialize, Serialize};
+
+use crate::{error::Error, models::Statement as StatementModel, Client};
+
+#[derive(Debug, Clone)]
+pub struct Statement {
+    login_name: String,
+    client: Client,
+}
+
+impl Statement {
+    pub fn new(client: Client, login_name: String) -> Self {
+        Statement { login_name, client }
+    }
+
+    pub async fn get_statements(
+        &mut self,
+        params: StatementParams<'_>,
+    ) -> Result<StatementResponse, Error> {
+        let access_token = self.client.ensure_token(&self.login_name).await?;
+        let (endpoint, api_version, http_client) = {
+            let state = self.client.state.read().unwrap();
+            (
+                // endpoint
+                format!("{}/{}", state.api_endpoint, "statements"),
+                state.api_version.clone(),
+                state.http_client.clone(),
+            )
+        };
+
+        let res = http_client
+            .get(endpoint)
+            .header("Api-Version", api_version)
+            .header(header::AUTHORIZATION, format!("Bearer {access_token}"))
+            .query(&[
+                ("accountId", params.account_id.map(|s| s.to_string())),
+                ("container", params.container.map(|s| s.to_string())),
+                ("fromDate", params.from_date.map(|s| s.to_string())),
+                ("isLatest", params.is_latest.map(|e| e.to_string())),
+                ("status", params.status.map(|s| s.to_string())),
+            ])
+            .send()
+            .await?;
+
+        if res.status().is_success() {
+            Ok(res.json().await?)
+        } else {
+            Err(Error::Api(res.json().await?))
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StatementParams<'a> {
+    pub account_id: Option<&'a str>,
+    /// One of `creditCard`, `loan` or `insurance`.
+    pub container: Option<&'a str>,
+    pub from_date: Option<&'a str>,
+    pub is_latest: Option<bool>,
+    pub status: Option<&'a str>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct StatementResponse {
+    pub statement: Option<Vec<StatementModel>>,
+}
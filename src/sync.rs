@@ -0,0 +1,660 @@
+use std::{collections::BTreeMap, future::Future};
+
+use chrono::{DateTime, Duration, SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    data_extracts::{DataExtracts, DataExtractsUserDataParams, DataExtractsUserDataResponse},
+    error::Error,
+    models::{Account, DataExtractsUserData, Holding, ProviderAccount, Transaction},
+    Client,
+};
+
+const DEFAULT_WINDOW_HOURS: i64 = 24;
+const DEFAULT_MAX_LOOKBACK_DAYS: i64 = 7;
+const DEFAULT_PAGE_SIZE: u32 = 500;
+
+/// How far a user has been synced. Persist it after `SyncSink::commit` and
+/// hand it back to the next `SyncEngine::sync`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncCursor {
+    pub login_name: String,
+    /// Changes up to this instant have been handed to the sink.
+    pub last_synced: DateTime<Utc>,
+}
+
+impl SyncCursor {
+    pub fn new(login_name: String, last_synced: DateTime<Utc>) -> Self {
+        SyncCursor {
+            login_name,
+            last_synced,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub enum RecordKind {
+    ProviderAccount,
+    Account,
+    Holding,
+    Transaction,
+}
+
+/// Identifies a record across changes. Ids are only unique per kind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct RecordKey {
+    pub kind: RecordKind,
+    pub id: i64,
+}
+
+#[derive(Debug)]
+pub enum Record {
+    ProviderAccount(Box<ProviderAccount>),
+    Account(Box<Account>),
+    Holding(Box<Holding>),
+    Transaction(Box<Transaction>),
+}
+
+impl Record {
+    pub fn kind(&self) -> RecordKind {
+        match self {
+            Record::ProviderAccount(_) => RecordKind::ProviderAccount,
+            Record::Account(_) => RecordKind::Account,
+            Record::Holding(_) => RecordKind::Holding,
+            Record::Transaction(_) => RecordKind::Transaction,
+        }
+    }
+
+    pub fn key(&self) -> Option<RecordKey> {
+        let id = match self {
+            Record::ProviderAccount(r) => r.id,
+            Record::Account(r) => r.id,
+            Record::Holding(r) => r.id,
+            Record::Transaction(r) => r.id,
+        }?;
+
+        Some(RecordKey {
+            kind: self.kind(),
+            id,
+        })
+    }
+
+    pub fn is_deleted(&self) -> bool {
+        match self {
+            Record::ProviderAccount(r) => r.is_deleted,
+            Record::Account(r) => r.is_deleted,
+            Record::Holding(r) => r.is_deleted,
+            Record::Transaction(r) => r.is_deleted,
+        }
+        .unwrap_or(false)
+    }
+}
+
+#[derive(Debug)]
+pub enum Change {
+    Upsert(Record),
+    Delete(RecordKey),
+}
+
+/// Changed records with duplicates merged away. Data extracts return the
+/// same record more than once when it changes repeatedly or spans pages, so
+/// only the change added last is kept for each `RecordKey`. Records without
+/// an id are dropped.
+#[derive(Debug, Default)]
+pub struct ChangeSet {
+    changes: BTreeMap<RecordKey, Change>,
+}
+
+impl ChangeSet {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.changes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    pub fn insert(&mut self, record: Record) {
+        let key = match record.key() {
+            Some(key) => key,
+            None => return,
+        };
+
+        let change = if record.is_deleted() {
+            Change::Delete(key)
+        } else {
+            Change::Upsert(record)
+        };
+        self.changes.insert(key, change);
+    }
+
+    pub fn extend_from_user_data(&mut self, user_data: DataExtractsUserData) {
+        let records = [
+            map_records(user_data.provider_account, Record::ProviderAccount),
+            map_records(user_data.account, Record::Account),
+            map_records(user_data.holding, Record::Holding),
+            map_records(user_data.transaction, Record::Transaction),
+        ];
+        for record in records.into_iter().flatten() {
+            self.insert(record);
+        }
+    }
+
+    /// Merges `other` into this set. Changes in `other` are taken to be the
+    /// more recent ones.
+    pub fn merge(&mut self, other: ChangeSet) {
+        self.changes.extend(other.changes);
+    }
+
+    /// Upserts come first, parents before children (provider accounts, then
+    /// accounts, then holdings and transactions). Deletes follow in the
+    /// reverse order so children go before their parents.
+    pub fn into_changes(self) -> impl Iterator<Item = Change> {
+        let (upserts, mut deletes): (Vec<_>, Vec<_>) = self
+            .changes
+            .into_values()
+            .partition(|change| matches!(change, Change::Upsert(_)));
+        deletes.reverse();
+
+        upserts.into_iter().chain(deletes)
+    }
+}
+
+fn map_records<T>(records: Option<Vec<T>>, f: fn(Box<T>) -> Record) -> Vec<Record> {
+    records.into_iter().flatten().map(Box::new).map(f).collect()
+}
+
+/// Receives the changes found by `SyncEngine`. Wrap your own errors with
+/// `Error::Sink`.
+pub trait SyncSink: Send {
+    fn upsert(
+        &mut self,
+        login_name: &str,
+        record: Record,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+
+    fn delete(
+        &mut self,
+        login_name: &str,
+        key: RecordKey,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+
+    /// Called once all changes up to `cursor.last_synced` have been handed
+    /// over. This is the place to persist the cursor, ideally in the same
+    /// transaction as the changes.
+    fn commit(&mut self, cursor: &SyncCursor) -> impl Future<Output = Result<(), Error>> + Send;
+}
+
+/// Brings a sink up to date with a user's data using data extracts. The
+/// time since the cursor is walked in windows; each window's changes are
+/// merged, handed to the sink and committed before moving on to the next, so
+/// a failed sync resumes from the last committed window.
+#[derive(Debug, Clone)]
+pub struct SyncEngine {
+    data_extracts: DataExtracts,
+    options: SyncOptions,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct SyncOptions {
+    window: Duration,
+    max_lookback: Duration,
+    page_size: u32,
+}
+
+impl Default for SyncOptions {
+    fn default() -> Self {
+        SyncOptions {
+            window: Duration::hours(DEFAULT_WINDOW_HOURS),
+            max_lookback: Duration::days(DEFAULT_MAX_LOOKBACK_DAYS),
+            page_size: DEFAULT_PAGE_SIZE,
+        }
+    }
+}
+
+impl SyncEngine {
+    pub fn new(client: Client) -> Self {
+        SyncEngine {
+            data_extracts: DataExtracts::new(client),
+            options: Default::default(),
+        }
+    }
+
+    /// The span of each data extracts call. Defaults to a day.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` isn't positive.
+    pub fn with_window(mut self, window: Duration) -> Self {
+        assert!(window > Duration::zero(), "sync window must be positive");
+        self.options.window = window;
+        self
+    }
+
+    /// How far back data extracts can go. Defaults to the 7 days Yodlee keeps
+    /// changes for.
+    pub fn with_max_lookback(mut self, max_lookback: Duration) -> Self {
+        self.options.max_lookback = max_lookback;
+        self
+    }
+
+    /// The number of transactions fetched per call. Defaults to 500.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `page_size` is 0.
+    pub fn with_page_size(mut self, page_size: u32) -> Self {
+        assert!(page_size > 0, "sync page size must be positive");
+        self.options.page_size = page_size;
+        self
+    }
+
+    /// Syncs from `cursor` up to now and returns the new cursor. Fails with
+    /// `Error::CursorExpired` if the cursor is older than the max lookback;
+    /// changes have been lost by then and the user needs a full resync.
+    pub async fn sync<S: SyncSink>(
+        &mut self,
+        cursor: SyncCursor,
+        sink: &mut S,
+    ) -> Result<SyncCursor, Error> {
+        run_sync(
+            &mut self.data_extracts,
+            self.options,
+            cursor,
+            sink,
+            Utc::now(),
+        )
+        .await
+    }
+}
+
+// Where the engine gets its changes from, so the sync loop can be driven
+// without the API.
+trait UserDataSource: Send {
+    fn get_user_data(
+        &mut self,
+        params: DataExtractsUserDataParams<'_>,
+    ) -> impl Future<Output = Result<DataExtractsUserDataResponse, Error>> + Send;
+}
+
+impl UserDataSource for DataExtracts {
+    fn get_user_data(
+        &mut self,
+        params: DataExtractsUserDataParams<'_>,
+    ) -> impl Future<Output = Result<DataExtractsUserDataResponse, Error>> + Send {
+        DataExtracts::get_user_data(self, params)
+    }
+}
+
+async fn run_sync<D: UserDataSource, S: SyncSink>(
+    source: &mut D,
+    options: SyncOptions,
+    mut cursor: SyncCursor,
+    sink: &mut S,
+    now: DateTime<Utc>,
+) -> Result<SyncCursor, Error> {
+    if cursor.last_synced < now - options.max_lookback {
+        return Err(Error::CursorExpired);
+    }
+
+    while cursor.last_synced < now {
+        let to_date = (cursor.last_synced + options.window).min(now);
+        let changes = get_changes(
+            source,
+            options,
+            &cursor.login_name,
+            cursor.last_synced,
+            to_date,
+        )
+        .await?;
+
+        for change in changes.into_changes() {
+            match change {
+                Change::Upsert(record) => sink.upsert(&cursor.login_name, record).await?,
+                Change::Delete(key) => sink.delete(&cursor.login_name, key).await?,
+            }
+        }
+
+        cursor.last_synced = to_date;
+        sink.commit(&cursor).await?;
+    }
+
+    Ok(cursor)
+}
+
+async fn get_changes<D: UserDataSource>(
+    source: &mut D,
+    options: SyncOptions,
+    login_name: &str,
+    from_date: DateTime<Utc>,
+    to_date: DateTime<Utc>,
+) -> Result<ChangeSet, Error> {
+    let from_date = from_date.to_rfc3339_opts(SecondsFormat::Secs, true);
+    let to_date = to_date.to_rfc3339_opts(SecondsFormat::Secs, true);
+
+    let mut changes = ChangeSet::new();
+    let mut skip = 0;
+    loop {
+        let res = source
+            .get_user_data(DataExtractsUserDataParams {
+                login_name,
+                from_date: &from_date,
+                to_date: &to_date,
+                skip: Some(skip),
+                top: Some(options.page_size),
+            })
+            .await?;
+
+        let mut transactions = 0;
+        for user_data in res.user_data.into_iter().flatten() {
+            transactions += user_data.transaction.as_ref().map_or(0, Vec::len);
+            changes.extend_from_user_data(user_data);
+        }
+
+        // only transactions are paged, so they decide when to stop: on a
+        // short page, or once the total is reached when the API gives one
+        skip += transactions as u32;
+        let short_page = transactions < options.page_size as usize;
+        let total_reached = res
+            .total_transactions_count
+            .is_some_and(|total| i64::from(skip) >= total);
+        if short_page || total_reached {
+            break;
+        }
+    }
+
+    Ok(changes)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+
+    use chrono::TimeZone;
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    #[derive(Debug, Default)]
+    struct FakeSource {
+        // (from_date, to_date, skip, top) of each call
+        calls: Vec<(String, String, u32, u32)>,
+        // responses handed out in order; empty ones once they run out
+        responses: VecDeque<Value>,
+    }
+
+    impl UserDataSource for FakeSource {
+        async fn get_user_data(
+            &mut self,
+            params: DataExtractsUserDataParams<'_>,
+        ) -> Result<DataExtractsUserDataResponse, Error> {
+            assert_eq!(params.login_name, "user1");
+            self.calls.push((
+                params.from_date.to_string(),
+                params.to_date.to_string(),
+                params.skip.unwrap(),
+                params.top.unwrap(),
+            ));
+
+            let res = self.responses.pop_front().unwrap_or_else(|| json!({}));
+            Ok(serde_json::from_value(res)?)
+        }
+    }
+
+    #[derive(Debug, Default)]
+    struct FakeSink {
+        log: Vec<String>,
+    }
+
+    impl SyncSink for FakeSink {
+        async fn upsert(&mut self, _login_name: &str, record: Record) -> Result<(), Error> {
+            let key = record.key().unwrap();
+            self.log.push(format!("upsert {:?} {}", key.kind, key.id));
+            Ok(())
+        }
+
+        async fn delete(&mut self, _login_name: &str, key: RecordKey) -> Result<(), Error> {
+            self.log.push(format!("delete {:?} {}", key.kind, key.id));
+            Ok(())
+        }
+
+        async fn commit(&mut self, cursor: &SyncCursor) -> Result<(), Error> {
+            self.log.push(format!("commit {}", cursor.last_synced));
+            Ok(())
+        }
+    }
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2023, 5, 8, 12, 0, 0).unwrap()
+    }
+
+    fn cursor(last_synced: DateTime<Utc>) -> SyncCursor {
+        SyncCursor::new("user1".to_string(), last_synced)
+    }
+
+    fn call(from_date: &str, to_date: &str, skip: u32, top: u32) -> (String, String, u32, u32) {
+        (from_date.to_string(), to_date.to_string(), skip, top)
+    }
+
+    #[tokio::test]
+    async fn test_sync_in_windows() {
+        let mut source = FakeSource::default();
+        let mut sink = FakeSink::default();
+
+        let last_synced = now() - Duration::hours(60);
+        let res = run_sync(
+            &mut source,
+            Default::default(),
+            cursor(last_synced),
+            &mut sink,
+            now(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(res, cursor(now()));
+        assert_eq!(
+            source.calls,
+            vec![
+                call("2023-05-06T00:00:00Z", "2023-05-07T00:00:00Z", 0, 500),
+                call("2023-05-07T00:00:00Z", "2023-05-08T00:00:00Z", 0, 500),
+                call("2023-05-08T00:00:00Z", "2023-05-08T12:00:00Z", 0, 500),
+            ]
+        );
+        assert_eq!(
+            sink.log,
+            vec![
+                "commit 2023-05-07 00:00:00 UTC",
+                "commit 2023-05-08 00:00:00 UTC",
+                "commit 2023-05-08 12:00:00 UTC",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sync_up_to_date() {
+        let mut source = FakeSource::default();
+        let mut sink = FakeSink::default();
+
+        let res = run_sync(
+            &mut source,
+            Default::default(),
+            cursor(now()),
+            &mut sink,
+            now(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(res, cursor(now()));
+        assert!(source.calls.is_empty());
+        assert!(sink.log.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_sync_cursor_expired() {
+        let mut source = FakeSource::default();
+        let mut sink = FakeSink::default();
+
+        let last_synced = now() - Duration::days(7) - Duration::seconds(1);
+        let res = run_sync(
+            &mut source,
+            Default::default(),
+            cursor(last_synced),
+            &mut sink,
+            now(),
+        )
+        .await;
+
+        assert!(matches!(res, Err(Error::CursorExpired)));
+        assert!(source.calls.is_empty());
+        assert!(sink.log.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_sync_pages_transactions() {
+        let mut source = FakeSource {
+            responses: VecDeque::from([
+                json!({
+                    "totalTransactionsCount": 5,
+                    "userData": [{
+                        "account": [{ "id": 1 }],
+                        "transaction": [{ "id": 10 }, { "id": 11 }],
+                    }],
+                }),
+                json!({
+                    "totalTransactionsCount": 5,
+                    "userData": [{
+                        "account": [{ "id": 1 }],
+                        "holding": [{ "id": 20, "isDeleted": true }],
+                        "transaction": [{ "id": 11, "isDeleted": true }, { "id": 12 }],
+                    }],
+                }),
+                json!({
+                    "totalTransactionsCount": 5,
+                    "userData": [{ "transaction": [{ "id": 13 }] }],
+                }),
+            ]),
+            ..Default::default()
+        };
+        let mut sink = FakeSink::default();
+
+        let options = SyncOptions {
+            page_size: 2,
+            ..Default::default()
+        };
+        let last_synced = now() - Duration::hours(1);
+        run_sync(&mut source, options, cursor(last_synced), &mut sink, now())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            source.calls,
+            vec![
+                call("2023-05-08T11:00:00Z", "2023-05-08T12:00:00Z", 0, 2),
+                call("2023-05-08T11:00:00Z", "2023-05-08T12:00:00Z", 2, 2),
+                call("2023-05-08T11:00:00Z", "2023-05-08T12:00:00Z", 4, 2),
+            ]
+        );
+        assert_eq!(
+            sink.log,
+            vec![
+                "upsert Account 1",
+                "upsert Transaction 10",
+                "upsert Transaction 12",
+                "upsert Transaction 13",
+                "delete Transaction 11",
+                "delete Holding 20",
+                "commit 2023-05-08 12:00:00 UTC",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sync_stops_on_empty_page() {
+        // a total that is never reached must not keep the loop going
+        let mut source = FakeSource {
+            responses: VecDeque::from([json!({
+                "totalTransactionsCount": 10,
+                "userData": [{ "transaction": [{ "id": 10 }] }],
+            })]),
+            ..Default::default()
+        };
+        let mut sink = FakeSink::default();
+
+        let options = SyncOptions {
+            page_size: 1,
+            ..Default::default()
+        };
+        let last_synced = now() - Duration::hours(1);
+        run_sync(&mut source, options, cursor(last_synced), &mut sink, now())
+            .await
+            .unwrap();
+
+        assert_eq!(source.calls.len(), 2);
+        assert_eq!(
+            sink.log,
+            vec!["upsert Transaction 10", "commit 2023-05-08 12:00:00 UTC"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sync_pages_without_total() {
+        let mut source = FakeSource {
+            responses: VecDeque::from([
+                json!({ "userData": [{ "transaction": [{ "id": 10 }, { "id": 11 }] }] }),
+                json!({ "userData": [{ "transaction": [{ "id": 12 }, { "id": 13 }] }] }),
+                json!({ "userData": [{ "transaction": [{ "id": 14 }] }] }),
+            ]),
+            ..Default::default()
+        };
+        let mut sink = FakeSink::default();
+
+        let options = SyncOptions {
+            page_size: 2,
+            ..Default::default()
+        };
+        let last_synced = now() - Duration::hours(1);
+        run_sync(&mut source, options, cursor(last_synced), &mut sink, now())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            source.calls,
+            vec![
+                call("2023-05-08T11:00:00Z", "2023-05-08T12:00:00Z", 0, 2),
+                call("2023-05-08T11:00:00Z", "2023-05-08T12:00:00Z", 2, 2),
+                call("2023-05-08T11:00:00Z", "2023-05-08T12:00:00Z", 4, 2),
+            ]
+        );
+        assert_eq!(
+            sink.log,
+            vec![
+                "upsert Transaction 10",
+                "upsert Transaction 11",
+                "upsert Transaction 12",
+                "upsert Transaction 13",
+                "upsert Transaction 14",
+                "commit 2023-05-08 12:00:00 UTC",
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "sync window must be positive")]
+    fn test_zero_window() {
+        let client = Client::new(
+            String::new(),
+            String::new(),
+            String::new(),
+            String::new(),
+            String::new(),
+        );
+        SyncEngine::new(client).with_window(Duration::zero());
+    }
+}
@@ -194,8 +194,8 @@ impl TokenManager {
             .tokens
             .write()
             .unwrap()
-            .iter()
-            .map(|(_, e)| e.close_tx.clone())
+            .values()
+            .map(|e| e.close_tx.clone())
             .collect::<Vec<_>>();
         for close_tx in channels {
             // we don't really care if this fails
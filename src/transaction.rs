@@ -0,0 +1,342 @@
+use std::collections::HashMap;
+
+use futures::Stream;
+use reqwest::header;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::Error,
+    models::{
+        Count, Transaction as TransactionModel, TransactionCategory, TransactionCategoryRequest,
+        TransactionUpdate, UpdateCategoryRequest,
+    },
+    paginator::{paginate, MAX_PAGE_SIZE},
+    Client,
+};
+
+#[derive(Debug, Clone)]
+pub struct Transaction {
+    login_name: String,
+    client: Client,
+}
+
+impl Transaction {
+    pub fn new(client: Client, login_name: String) -> Self {
+        Transaction { login_name, client }
+    }
+
+    pub async fn get_transactions(
+        &mut self,
+        params: TransactionParams<'_>,
+    ) -> Result<TransactionResponse, Error> {
+        let access_token = self.client.ensure_token(&self.login_name).await?;
+        let (endpoint, api_version, http_client) = {
+            let state = self.client.state.read().unwrap();
+            (
+                // endpoint
+                format!("{}/{}", state.api_endpoint, "transactions"),
+                state.api_version.clone(),
+                state.http_client.clone(),
+            )
+        };
+
+        let res = http_client
+            .get(endpoint)
+            .header("Api-Version", api_version)
+            .header(header::AUTHORIZATION, format!("Bearer {access_token}"))
+            .query(&[
+                ("fromDate", params.from_date.map(|s| s.to_string())),
+                ("toDate", params.to_date.map(|s| s.to_string())),
+                ("accountId", params.account_id.map(|s| s.to_string())),
+                ("categoryId", params.category_id.map(|s| s.to_string())),
+                ("container", params.container.map(|s| s.to_string())),
+                ("baseType", params.base_type.map(|s| s.to_string())),
+                ("keyword", params.keyword.map(|s| s.to_string())),
+                ("skip", params.skip.map(|s| s.to_string())),
+                ("top", params.top.map(|s| s.to_string())),
+                (
+                    "highLevelCategoryId",
+                    params.high_level_category_id.map(|s| s.to_string()),
+                ),
+                ("type", params.transaction_type.map(|s| s.to_string())),
+            ])
+            .send()
+            .await?;
+
+        if res.status().is_success() {
+            Ok(res.json().await?)
+        } else {
+            Err(Error::Api(res.json().await?))
+        }
+    }
+
+    /// Streams every transaction matching `params`, fetching further pages as
+    /// the stream is polled. `params.top` is used as the page size.
+    pub fn get_transactions_stream<'a>(
+        &self,
+        params: TransactionParams<'a>,
+    ) -> impl Stream<Item = Result<TransactionModel, Error>> + 'a {
+        let this = self.clone();
+        paginate(
+            params.skip.unwrap_or(0),
+            params.top.unwrap_or(MAX_PAGE_SIZE),
+            move |skip, top| {
+                let mut this = this.clone();
+                async move {
+                    let params = TransactionParams {
+                        skip: Some(skip),
+                        top: Some(top),
+                        ..params
+                    };
+                    let page = this
+                        .get_transactions(params)
+                        .await?
+                        .transaction
+                        .unwrap_or_default();
+                    let count = page.len();
+                    Ok((page, count))
+                }
+            },
+        )
+    }
+
+    /// Counts the transactions matching `params`. `skip` and `top` are ignored.
+    pub async fn get_transactions_count(
+        &mut self,
+        params: TransactionParams<'_>,
+    ) -> Result<TransactionCountResponse, Error> {
+        let access_token = self.client.ensure_token(&self.login_name).await?;
+        let (endpoint, api_version, http_client) = {
+            let state = self.client.state.read().unwrap();
+            (
+                // endpoint
+                format!("{}/{}", state.api_endpoint, "transactions/count"),
+                state.api_version.clone(),
+                state.http_client.clone(),
+            )
+        };
+
+        let res = http_client
+            .get(endpoint)
+            .header("Api-Version", api_version)
+            .header(header::AUTHORIZATION, format!("Bearer {access_token}"))
+            .query(&[
+                ("fromDate", params.from_date.map(|s| s.to_string())),
+                ("toDate", params.to_date.map(|s| s.to_string())),
+                ("accountId", params.account_id.map(|s| s.to_string())),
+                ("categoryId", params.category_id.map(|s| s.to_string())),
+                ("container", params.container.map(|s| s.to_string())),
+                ("baseType", params.base_type.map(|s| s.to_string())),
+                ("keyword", params.keyword.map(|s| s.to_string())),
+                (
+                    "highLevelCategoryId",
+                    params.high_level_category_id.map(|s| s.to_string()),
+                ),
+                ("type", params.transaction_type.map(|s| s.to_string())),
+            ])
+            .send()
+            .await?;
+
+        if res.status().is_success() {
+            Ok(res.json().await?)
+        } else {
+            Err(Error::Api(res.json().await?))
+        }
+    }
+
+    pub async fn update(
+        &mut self,
+        transaction_id: String,
+        transaction: TransactionUpdate,
+    ) -> Result<(), Error> {
+        let access_token = self.client.ensure_token(&self.login_name).await?;
+        let (endpoint, api_version, http_client) = {
+            let state = self.client.state.read().unwrap();
+            (
+                // endpoint
+                format!(
+                    "{}/{}/{}",
+                    state.api_endpoint, "transactions", transaction_id
+                ),
+                state.api_version.clone(),
+                state.http_client.clone(),
+            )
+        };
+
+        let req = TransactionUpdateRequest { transaction };
+        let res = http_client
+            .put(endpoint)
+            .header("Api-Version", api_version)
+            .header(header::AUTHORIZATION, format!("Bearer {access_token}"))
+            .json(&req)
+            .send()
+            .await?;
+
+        if res.status().is_success() {
+            Ok(())
+        } else {
+            Err(Error::Api(res.json().await?))
+        }
+    }
+
+    pub async fn get_categories(&mut self) -> Result<TransactionCategoryResponse, Error> {
+        let access_token = self.client.ensure_token(&self.login_name).await?;
+        let (endpoint, api_version, http_client) = {
+            let state = self.client.state.read().unwrap();
+            (
+                // endpoint
+                format!("{}/{}", state.api_endpoint, "transactions/categories"),
+                state.api_version.clone(),
+                state.http_client.clone(),
+            )
+        };
+
+        let res = http_client
+            .get(endpoint)
+            .header("Api-Version", api_version)
+            .header(header::AUTHORIZATION, format!("Bearer {access_token}"))
+            .send()
+            .await?;
+
+        if res.status().is_success() {
+            Ok(res.json().await?)
+        } else {
+            Err(Error::Api(res.json().await?))
+        }
+    }
+
+    pub async fn create_category(
+        &mut self,
+        category: TransactionCategoryRequest,
+    ) -> Result<(), Error> {
+        let access_token = self.client.ensure_token(&self.login_name).await?;
+        let (endpoint, api_version, http_client) = {
+            let state = self.client.state.read().unwrap();
+            (
+                // endpoint
+                format!("{}/{}", state.api_endpoint, "transactions/categories"),
+                state.api_version.clone(),
+                state.http_client.clone(),
+            )
+        };
+
+        let res = http_client
+            .post(endpoint)
+            .header("Api-Version", api_version)
+            .header(header::AUTHORIZATION, format!("Bearer {access_token}"))
+            .json(&category)
+            .send()
+            .await?;
+
+        if res.status().is_success() {
+            Ok(())
+        } else {
+            Err(Error::Api(res.json().await?))
+        }
+    }
+
+    pub async fn update_category(&mut self, category: UpdateCategoryRequest) -> Result<(), Error> {
+        let access_token = self.client.ensure_token(&self.login_name).await?;
+        let (endpoint, api_version, http_client) = {
+            let state = self.client.state.read().unwrap();
+            (
+                // endpoint
+                format!("{}/{}", state.api_endpoint, "transactions/categories"),
+                state.api_version.clone(),
+                state.http_client.clone(),
+            )
+        };
+
+        let res = http_client
+            .put(endpoint)
+            .header("Api-Version", api_version)
+            .header(header::AUTHORIZATION, format!("Bearer {access_token}"))
+            .json(&category)
+            .send()
+            .await?;
+
+        if res.status().is_success() {
+            Ok(())
+        } else {
+            Err(Error::Api(res.json().await?))
+        }
+    }
+
+    /// Deletes a user defined category. System categories cannot be deleted.
+    pub async fn delete_category(&mut self, category_id: String) -> Result<(), Error> {
+        let access_token = self.client.ensure_token(&self.login_name).await?;
+        let (endpoint, api_version, http_client) = {
+            let state = self.client.state.read().unwrap();
+            (
+                // endpoint
+                format!(
+                    "{}/{}/{}",
+                    state.api_endpoint, "transactions/categories", category_id
+                ),
+                state.api_version.clone(),
+                state.http_client.clone(),
+            )
+        };
+
+        let res = http_client
+            .delete(endpoint)
+            .header("Api-Version", api_version)
+            .header(header::AUTHORIZATION, format!("Bearer {access_token}"))
+            .send()
+            .await?;
+
+        if res.status().is_success() {
+            Ok(())
+        } else {
+            Err(Error::Api(res.json().await?))
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct TransactionParams<'a> {
+    pub from_date: Option<&'a str>,
+    pub to_date: Option<&'a str>,
+    pub account_id: Option<&'a str>,
+    pub category_id: Option<&'a str>,
+    pub container: Option<&'a str>,
+    pub base_type: Option<&'a str>,
+    pub keyword: Option<&'a str>,
+    pub skip: Option<u32>,
+    pub top: Option<u32>,
+    pub high_level_category_id: Option<&'a str>,
+    pub transaction_type: Option<&'a str>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct TransactionResponse {
+    pub transaction: Option<Vec<TransactionModel>>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct TransactionCountResponse {
+    pub transaction: Option<Count>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionCategoryResponse {
+    pub transaction_category: Option<Vec<TransactionCategory>>,
+}
+
+impl TransactionCategoryResponse {
+    /// Maps every category ID to the ID of the high level category it rolls up
+    /// into, which is what spending should be grouped by at the top level.
+    pub fn high_level_category_map(&self) -> HashMap<i64, i64> {
+        self.transaction_category
+            .iter()
+            .flatten()
+            .filter_map(|c| Some((c.id?, c.high_level_category_id?)))
+            .collect()
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct TransactionUpdateRequest {
+    transaction: TransactionUpdate,
+}
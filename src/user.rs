@@ -1,5 +1,8 @@
+use std::collections::BTreeMap;
+
 use reqwest::header;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 use crate::{error::Error, models::User as UserModel, Client};
 
@@ -25,6 +28,31 @@ impl User {
         self.client.ensure_token(&self.login_name).await
     }
 
+    /// Builds everything FastLink 4 needs to launch for this user. The result
+    /// serializes to the options object `window.fastlink.open` takes.
+    pub async fn get_fastlink_config(
+        &mut self,
+        params: FastLinkParams,
+    ) -> Result<FastLinkConfig, Error> {
+        let access_token = self.client.ensure_token(&self.login_name).await?;
+        let fastlink_url = {
+            let state = self.client.state.read().unwrap();
+            match &state.fastlink_url {
+                Some(fastlink_url) => fastlink_url.clone(),
+                None if state.api_endpoint.contains(SANDBOX_API_HOST) => {
+                    SANDBOX_FASTLINK_URL.to_string()
+                }
+                None => return Err(Error::NoFastLinkUrl),
+            }
+        };
+
+        Ok(FastLinkConfig {
+            fastlink_url,
+            access_token: format!("Bearer {access_token}"),
+            params,
+        })
+    }
+
     pub async fn get_details(&mut self) -> Result<UserDetailsResponse, Error> {
         let access_token = self.client.ensure_token(&self.login_name).await?;
         let (endpoint, api_version, http_client) = {
@@ -77,3 +105,41 @@ impl User {
         }
     }
 }
+
+const SANDBOX_API_HOST: &str = "sandbox.api.yodlee.com";
+const SANDBOX_FASTLINK_URL: &str =
+    "https://fl4.sandbox.yodlee.com/authenticate/restserver/fastlink";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FastLinkConfig {
+    #[serde(rename = "fastLinkURL")]
+    pub fastlink_url: String,
+    pub access_token: String,
+    pub params: FastLinkParams,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FastLinkParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub config_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flow: Option<FastLinkFlow>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider_account_id: Option<i64>,
+    /// Any other deeplink parameters, passed through as is.
+    #[serde(flatten)]
+    pub deeplink: BTreeMap<String, Value>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum FastLinkFlow {
+    Add,
+    Edit,
+    Refresh,
+    ManageConsent,
+}
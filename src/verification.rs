@@ -0,0 +1,354 @@
+use reqwest::header;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::Error,
+    models::{
+        Account as AccountModel, Verification as VerificationModel, VerifiedAccount,
+        VerifyAccountRequest, VerifyTransactionCriteria,
+    },
+    Client,
+};
+
+#[derive(Debug, Clone)]
+pub struct Verification {
+    login_name: String,
+    client: Client,
+}
+
+impl Verification {
+    pub fn new(client: Client, login_name: String) -> Self {
+        Verification { login_name, client }
+    }
+
+    /// Starts a challenge deposit verification. `verification` should carry
+    /// the `CHALLENGE_DEPOSIT` type and the account to deposit into.
+    pub async fn initiate(
+        &mut self,
+        verification: VerificationModel,
+    ) -> Result<VerificationResponse, Error> {
+        let access_token = self.client.ensure_token(&self.login_name).await?;
+        let (endpoint, api_version, http_client) = {
+            let state = self.client.state.read().unwrap();
+            (
+                // endpoint
+                format!("{}/{}", state.api_endpoint, "verification"),
+                state.api_version.clone(),
+                state.http_client.clone(),
+            )
+        };
+
+        let req = VerificationRequest { verification };
+        let res = http_client
+            .post(endpoint)
+            .header("Api-Version", api_version)
+            .header(header::AUTHORIZATION, format!("Bearer {access_token}"))
+            .json(&req)
+            .send()
+            .await?;
+
+        if res.status().is_success() {
+            Ok(res.json().await?)
+        } else {
+            Err(Error::Api(res.json().await?))
+        }
+    }
+
+    /// Completes a challenge deposit verification with the deposit amounts
+    /// the user saw in their account.
+    pub async fn verify(
+        &mut self,
+        verification: VerificationModel,
+    ) -> Result<VerificationResponse, Error> {
+        let access_token = self.client.ensure_token(&self.login_name).await?;
+        let (endpoint, api_version, http_client) = {
+            let state = self.client.state.read().unwrap();
+            (
+                // endpoint
+                format!("{}/{}", state.api_endpoint, "verification"),
+                state.api_version.clone(),
+                state.http_client.clone(),
+            )
+        };
+
+        let req = VerificationRequest { verification };
+        let res = http_client
+            .put(endpoint)
+            .header("Api-Version", api_version)
+            .header(header::AUTHORIZATION, format!("Bearer {access_token}"))
+            .json(&req)
+            .send()
+            .await?;
+
+        if res.status().is_success() {
+            Ok(res.json().await?)
+        } else {
+            Err(Error::Api(res.json().await?))
+        }
+    }
+
+    pub async fn get_status(
+        &mut self,
+        params: VerificationParams<'_>,
+    ) -> Result<VerificationResponse, Error> {
+        let access_token = self.client.ensure_token(&self.login_name).await?;
+        let (endpoint, api_version, http_client) = {
+            let state = self.client.state.read().unwrap();
+            (
+                // endpoint
+                format!("{}/{}", state.api_endpoint, "verification"),
+                state.api_version.clone(),
+                state.http_client.clone(),
+            )
+        };
+
+        let res = http_client
+            .get(endpoint)
+            .header("Api-Version", api_version)
+            .header(header::AUTHORIZATION, format!("Bearer {access_token}"))
+            .query(&[
+                ("accountId", params.account_id.map(|s| s.to_string())),
+                (
+                    "providerAccountId",
+                    params.provider_account_id.map(|s| s.to_string()),
+                ),
+                (
+                    "verificationType",
+                    params.verification_type.map(|s| s.to_string()),
+                ),
+            ])
+            .send()
+            .await?;
+
+        if res.status().is_success() {
+            Ok(res.json().await?)
+        } else {
+            Err(Error::Api(res.json().await?))
+        }
+    }
+
+    /// Returns the accounts verified through instant account verification,
+    /// along with their holders and full account numbers.
+    pub async fn get_verified_accounts(
+        &mut self,
+        params: VerifiedAccountParams<'_>,
+    ) -> Result<VerifiedAccountResponse, Error> {
+        let access_token = self.client.ensure_token(&self.login_name).await?;
+        let (endpoint, api_version, http_client) = {
+            let state = self.client.state.read().unwrap();
+            (
+                // endpoint
+                format!("{}/{}", state.api_endpoint, "verification/verifiedAccounts"),
+                state.api_version.clone(),
+                state.http_client.clone(),
+            )
+        };
+
+        let res = http_client
+            .get(endpoint)
+            .header("Api-Version", api_version)
+            .header(header::AUTHORIZATION, format!("Bearer {access_token}"))
+            .query(&[
+                (
+                    "providerAccountId",
+                    params.provider_account_id.map(|s| s.to_string()),
+                ),
+                ("accountId", params.account_ids.map(|e| e.join(","))),
+                ("isSelected", params.is_selected.map(|e| e.to_string())),
+                (
+                    "verificationStatus",
+                    params.verification_status.map(|s| s.to_string()),
+                ),
+            ])
+            .send()
+            .await?;
+
+        if res.status().is_success() {
+            Ok(res.json().await?)
+        } else {
+            Err(Error::Api(res.json().await?))
+        }
+    }
+
+    /// Checks that transactions matching `req`'s criteria exist in the
+    /// provider account's accounts.
+    pub async fn verify_account(
+        &mut self,
+        provider_account_id: String,
+        req: VerifyAccountRequest,
+    ) -> Result<VerifyAccountResponse, Error> {
+        let access_token = self.client.ensure_token(&self.login_name).await?;
+        let (endpoint, api_version, http_client) = {
+            let state = self.client.state.read().unwrap();
+            (
+                // endpoint
+                format!(
+                    "{}/{}/{}",
+                    state.api_endpoint, "verifyAccount", provider_account_id
+                ),
+                state.api_version.clone(),
+                state.http_client.clone(),
+            )
+        };
+
+        let res = http_client
+            .post(endpoint)
+            .header("Api-Version", api_version)
+            .header(header::AUTHORIZATION, format!("Bearer {access_token}"))
+            .json(&req)
+            .send()
+            .await?;
+
+        if res.status().is_success() {
+            Ok(res.json().await?)
+        } else {
+            Err(Error::Api(res.json().await?))
+        }
+    }
+}
+
+/// Matches a verified account against the holder name and account number a
+/// user gave us. Names are compared ignoring case, punctuation and the order
+/// of the words, and match when every word of the shorter name is in the
+/// longer one, so a left out middle name still matches. A single letter
+/// stands for any word starting with it, and the shorter name needs at least
+/// two words unless the longer one has just one. Account numbers are compared
+/// on their digits against the full (unmasked) account numbers.
+pub fn match_account(
+    account: &VerifiedAccount,
+    holder_name: &str,
+    account_number: &str,
+) -> AccountMatch {
+    let holder_name = name_words(holder_name);
+    let holder_name = account
+        .holder
+        .iter()
+        .flatten()
+        .filter_map(|holder| holder.name.as_ref())
+        .any(|name| {
+            let full_name = name.full_name.clone().unwrap_or_else(|| {
+                [&name.first, &name.middle, &name.last]
+                    .into_iter()
+                    .flatten()
+                    .cloned()
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            });
+            names_match(&name_words(&full_name), &holder_name)
+        });
+
+    let account_number = digits(account_number);
+    let account_number = account
+        .full_account_number_list
+        .iter()
+        .flat_map(|list| {
+            [
+                list.unmasked_account_number.as_deref(),
+                list.payment_account_number.as_deref(),
+            ]
+        })
+        .flatten()
+        .any(|number| !account_number.is_empty() && digits(number) == account_number);
+
+    AccountMatch {
+        holder_name,
+        account_number,
+    }
+}
+
+fn name_words(name: &str) -> Vec<String> {
+    let mut words = name
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(|w| w.to_lowercase())
+        .collect::<Vec<_>>();
+    words.sort();
+    words
+}
+
+fn names_match(a: &[String], b: &[String]) -> bool {
+    let (shorter, longer) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+    if shorter.is_empty() || shorter.len() < longer.len().min(2) {
+        return false;
+    }
+
+    // match whole words before initials, and exact matches before initials,
+    // so an initial can't take a word that is needed for an exact match
+    let (initials, words): (Vec<_>, Vec<_>) = shorter.iter().partition(|w| is_initial(w));
+    let mut remaining = longer.iter().collect::<Vec<_>>();
+    words.into_iter().chain(initials).all(|word| {
+        let found = remaining.iter().position(|w| *w == word).or_else(|| {
+            remaining
+                .iter()
+                .position(|w| is_initial_of(w, word) || is_initial_of(word, w))
+        });
+        found.map(|i| remaining.remove(i)).is_some()
+    })
+}
+
+fn is_initial(word: &str) -> bool {
+    word.chars().count() == 1
+}
+
+fn is_initial_of(initial: &str, word: &str) -> bool {
+    is_initial(initial) && word.starts_with(initial)
+}
+
+fn digits(number: &str) -> String {
+    number.chars().filter(char::is_ascii_digit).collect()
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AccountMatch {
+    pub holder_name: bool,
+    pub account_number: bool,
+}
+
+impl AccountMatch {
+    pub fn is_match(&self) -> bool {
+        self.holder_name && self.account_number
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct VerificationParams<'a> {
+    pub account_id: Option<&'a str>,
+    pub provider_account_id: Option<&'a str>,
+    pub verification_type: Option<&'a str>,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct VerifiedAccountParams<'a> {
+    pub provider_account_id: Option<&'a str>,
+    pub account_ids: Option<&'a [&'a str]>,
+    pub is_selected: Option<bool>,
+    pub verification_status: Option<&'a str>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct VerificationResponse {
+    pub verification: Option<Vec<VerificationModel>>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerifiedAccountResponse {
+    pub request_id: Option<String>,
+    pub request_date: Option<String>,
+    pub state: Option<String>,
+    pub verification_status: Option<String>,
+    pub failed_reason: Option<String>,
+    pub verified_account: Option<Vec<VerifiedAccount>>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerifyAccountResponse {
+    pub transaction_criteria: Option<Vec<VerifyTransactionCriteria>>,
+    pub account: Option<Vec<AccountModel>>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct VerificationRequest {
+    verification: VerificationModel,
+}
@@ -0,0 +1,214 @@
+use std::future::Future;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tokio::sync::mpsc::Sender;
+
+use crate::{
+    error::Error,
+    models::{Account, AutoRefresh, DataExtractsEventData, EventName, ProviderAccount},
+};
+
+/// An event notification posted by Yodlee to a subscribed callback URL.
+#[derive(Debug)]
+pub enum Event {
+    Refresh(RefreshEvent),
+    DataUpdates(DataUpdatesEvent),
+    AutoRefreshUpdates(AutoRefreshUpdatesEvent),
+    LatestBalanceUpdates(LatestBalanceUpdatesEvent),
+    /// An event this crate does not know about, as it was posted.
+    Unknown(Value),
+}
+
+impl Event {
+    /// Parses the body of a callback request.
+    pub fn from_slice(payload: &[u8]) -> Result<Self, Error> {
+        let payload: Payload = serde_json::from_slice(payload)?;
+        let event = payload.event;
+
+        Ok(match event_name(&event) {
+            Some(EventName::Refresh) => Event::Refresh(serde_json::from_value(event)?),
+            Some(EventName::DataUpdates) => Event::DataUpdates(serde_json::from_value(event)?),
+            Some(EventName::AutoRefreshUpdates) => {
+                Event::AutoRefreshUpdates(serde_json::from_value(event)?)
+            }
+            Some(EventName::LatestBalanceUpdates) => {
+                Event::LatestBalanceUpdates(serde_json::from_value(event)?)
+            }
+            None => Event::Unknown(event),
+        })
+    }
+
+    pub fn name(&self) -> Option<EventName> {
+        match self {
+            Event::Refresh(_) => Some(EventName::Refresh),
+            Event::DataUpdates(_) => Some(EventName::DataUpdates),
+            Event::AutoRefreshUpdates(_) => Some(EventName::AutoRefreshUpdates),
+            Event::LatestBalanceUpdates(_) => Some(EventName::LatestBalanceUpdates),
+            Event::Unknown(_) => None,
+        }
+    }
+}
+
+// Not every event carries a `name`, but those that don't have an `info` such
+// as `REFRESH.PROCESS_COMPLETED` that starts with it.
+fn event_name(event: &Value) -> Option<EventName> {
+    if let Some(name) = event.get("name").and_then(Value::as_str) {
+        if let Ok(name) = serde_json::from_value(Value::from(name)) {
+            return Some(name);
+        }
+    }
+
+    let info = event.get("info").and_then(Value::as_str)?;
+    match info.split('.').next()? {
+        "REFRESH" => Some(EventName::Refresh),
+        "DATA_UPDATES" => Some(EventName::DataUpdates),
+        "AUTO_REFRESH" | "AUTO_REFRESH_UPDATES" => Some(EventName::AutoRefreshUpdates),
+        "LATEST_BALANCE" | "LATEST_BALANCE_UPDATES" => Some(EventName::LatestBalanceUpdates),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct Payload {
+    event: Value,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RefreshEvent {
+    pub info: Option<String>,
+    pub login_name: Option<String>,
+    pub data: Option<RefreshEventData>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RefreshEventData {
+    pub provider_account: Option<Vec<ProviderAccount>>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DataUpdatesEvent {
+    pub notification_id: Option<String>,
+    pub info: Option<String>,
+    /// One entry per user with changes, each linking to the
+    /// `dataExtracts/userData` call that fetches them.
+    pub data: Option<DataExtractsEventData>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AutoRefreshUpdatesEvent {
+    pub info: Option<String>,
+    pub login_name: Option<String>,
+    pub data: Option<AutoRefreshEventData>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AutoRefreshEventData {
+    pub provider_account: Option<Vec<AutoRefreshProviderAccount>>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AutoRefreshProviderAccount {
+    pub id: Option<i64>,
+    pub account: Option<Vec<AutoRefreshAccount>>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AutoRefreshAccount {
+    pub id: Option<i64>,
+    pub auto_refresh: Option<AutoRefresh>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LatestBalanceUpdatesEvent {
+    pub info: Option<String>,
+    pub login_name: Option<String>,
+    pub data: Option<LatestBalanceEventData>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LatestBalanceEventData {
+    pub provider_account_id: Option<i64>,
+    pub account: Option<Vec<Account>>,
+}
+
+/// Receives parsed events. Implement the `on_*` methods for the events of
+/// interest, or override `handle` to take every event in one place.
+pub trait EventHandler: Send + Sync {
+    fn handle(&self, event: Event) -> impl Future<Output = ()> + Send {
+        async move {
+            match event {
+                Event::Refresh(event) => self.on_refresh(event).await,
+                Event::DataUpdates(event) => self.on_data_updates(event).await,
+                Event::AutoRefreshUpdates(event) => self.on_auto_refresh_updates(event).await,
+                Event::LatestBalanceUpdates(event) => self.on_latest_balance_updates(event).await,
+                Event::Unknown(event) => self.on_unknown(event).await,
+            }
+        }
+    }
+
+    fn on_refresh(&self, _event: RefreshEvent) -> impl Future<Output = ()> + Send {
+        async {}
+    }
+
+    fn on_data_updates(&self, _event: DataUpdatesEvent) -> impl Future<Output = ()> + Send {
+        async {}
+    }
+
+    fn on_auto_refresh_updates(
+        &self,
+        _event: AutoRefreshUpdatesEvent,
+    ) -> impl Future<Output = ()> + Send {
+        async {}
+    }
+
+    fn on_latest_balance_updates(
+        &self,
+        _event: LatestBalanceUpdatesEvent,
+    ) -> impl Future<Output = ()> + Send {
+        async {}
+    }
+
+    fn on_unknown(&self, _event: Value) -> impl Future<Output = ()> + Send {
+        async {}
+    }
+}
+
+/// Forwards every event to the channel. Events are dropped if the receiver
+/// has gone away.
+impl EventHandler for Sender<Event> {
+    async fn handle(&self, event: Event) {
+        let _ = self.send(event).await;
+    }
+}
+
+/// Parses raw callback bodies and hands the events to an `EventHandler`.
+#[derive(Debug, Clone)]
+pub struct Dispatcher<H> {
+    handler: H,
+}
+
+impl<H: EventHandler> Dispatcher<H> {
+    pub fn new(handler: H) -> Self {
+        Dispatcher { handler }
+    }
+
+    pub fn handler(&self) -> &H {
+        &self.handler
+    }
+
+    pub async fn dispatch(&self, payload: &[u8]) -> Result<(), Error> {
+        let event = Event::from_slice(payload)?;
+        self.handler.handle(event).await;
+        Ok(())
+    }
+}
@@ -0,0 +1,99 @@
+use std::future::Future;
+
+use axum::{body::Bytes, extract::State, http::StatusCode, routing::post, Router};
+use tokio::{net::TcpListener, sync::mpsc};
+
+use crate::{
+    error::Error,
+    webhook::{Event, EventHandler},
+};
+
+const DEFAULT_PATH: &str = "/";
+const DEFAULT_QUEUE_SIZE: usize = 64;
+
+/// An HTTP listener for Yodlee event callbacks. Each callback is parsed,
+/// queued and acknowledged; a single worker task takes events off the queue
+/// and hands them to the handler one at a time, in the order they were
+/// received. A slow handler therefore doesn't hold up acknowledgements until
+/// the queue fills up, after which callbacks wait for room in the queue
+/// before being acknowledged.
+///
+/// Callbacks that can't be parsed are rejected with `400 Bad Request`, and
+/// callbacks that arrive after the worker has stopped with
+/// `503 Service Unavailable`.
+#[derive(Debug)]
+pub struct WebhookServer<H> {
+    handler: H,
+    path: String,
+    queue_size: usize,
+}
+
+impl<H: EventHandler + 'static> WebhookServer<H> {
+    pub fn new(handler: H) -> Self {
+        WebhookServer {
+            handler,
+            path: DEFAULT_PATH.to_string(),
+            queue_size: DEFAULT_QUEUE_SIZE,
+        }
+    }
+
+    /// The path Yodlee posts callbacks to. Defaults to `/`.
+    pub fn with_path(mut self, path: impl Into<String>) -> Self {
+        self.path = path.into();
+        self
+    }
+
+    /// The number of events that can wait for the handler. Defaults to 64.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `queue_size` is 0.
+    pub fn with_queue_size(mut self, queue_size: usize) -> Self {
+        assert!(queue_size > 0, "queue size must be positive");
+        self.queue_size = queue_size;
+        self
+    }
+
+    /// Returns the receiver as a router, for mounting it in an existing axum
+    /// application instead of calling `serve`. This spawns the worker task,
+    /// so it must be called from within a Tokio runtime.
+    pub fn into_router(self) -> Router {
+        let (tx, mut rx) = mpsc::channel(self.queue_size);
+        let handler = self.handler;
+        tokio::spawn(async move {
+            while let Some(event) = rx.recv().await {
+                handler.handle(event).await;
+            }
+        });
+
+        Router::new()
+            .route(&self.path, post(receive))
+            .with_state(tx)
+    }
+
+    pub async fn serve(self, listener: TcpListener) -> Result<(), Error> {
+        Ok(axum::serve(listener, self.into_router()).await?)
+    }
+
+    /// Like `serve`, but stops accepting callbacks once `signal` completes.
+    pub async fn serve_with_shutdown<F>(self, listener: TcpListener, signal: F) -> Result<(), Error>
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        Ok(axum::serve(listener, self.into_router())
+            .with_graceful_shutdown(signal)
+            .await?)
+    }
+}
+
+async fn receive(State(queue): State<mpsc::Sender<Event>>, body: Bytes) -> StatusCode {
+    let event = match Event::from_slice(&body) {
+        Ok(event) => event,
+        Err(_) => return StatusCode::BAD_REQUEST,
+    };
+
+    match queue.send(event).await {
+        Ok(()) => StatusCode::OK,
+        Err(_) => StatusCode::SERVICE_UNAVAILABLE,
+    }
+}
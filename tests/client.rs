@@ -1,5 +1,14 @@
+use futures::TryStreamExt;
 use serde::Deserialize;
-use yodlee_rs::{models::UserRegistration, Client};
+use yodlee_rs::{
+    derived::DerivedNetworthParams,
+    holding::HoldingParams,
+    models::UserRegistration,
+    provider::ProviderParams,
+    statement::StatementParams,
+    user::{FastLinkFlow, FastLinkParams},
+    Client,
+};
 
 #[derive(Deserialize, Clone, Debug)]
 struct Config {
@@ -29,7 +38,7 @@ fn make_client() -> (Config, Client) {
 #[tokio::test]
 async fn test_client_open() {
     let (_, mut client) = make_client();
-    let _ = client.open().await.unwrap();
+    client.open().await.unwrap();
     assert!(client.is_open());
     client.close().await.unwrap();
 }
@@ -37,7 +46,7 @@ async fn test_client_open() {
 #[tokio::test]
 async fn test_get_user_details() {
     let (config, mut client) = make_client();
-    let _ = client.open().await.unwrap();
+    client.open().await.unwrap();
 
     let mut user = client.user(config.test_user1.clone()).await.unwrap();
     let res = user.get_details().await.unwrap();
@@ -51,7 +60,7 @@ async fn test_get_user_details() {
 //#[tokio::test]
 async fn _test_user_registration() {
     let (_, mut client) = make_client();
-    let _ = client.open().await.unwrap();
+    client.open().await.unwrap();
 
     let user_registration = UserRegistration {
         login_name: "test_user5".to_string(),
@@ -68,7 +77,7 @@ async fn _test_user_registration() {
 #[tokio::test]
 async fn test_get_accounts() {
     let (config, mut client) = make_client();
-    let _ = client.open().await.unwrap();
+    client.open().await.unwrap();
 
     let mut account = client.account(config.test_user1.clone());
     let res = account.get_accounts(Default::default()).await.unwrap();
@@ -77,3 +86,187 @@ async fn test_get_accounts() {
 
     client.close().await.unwrap();
 }
+
+#[tokio::test]
+async fn test_get_transactions() {
+    let (config, mut client) = make_client();
+    client.open().await.unwrap();
+
+    let mut transaction = client.transaction(config.test_user1.clone());
+    let res = transaction
+        .get_transactions(Default::default())
+        .await
+        .unwrap();
+
+    println!("{:#?}", res);
+
+    client.close().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_get_transactions_stream() {
+    let (config, mut client) = make_client();
+    client.open().await.unwrap();
+
+    let mut transaction = client.transaction(config.test_user1.clone());
+    let count = transaction
+        .get_transactions_count(Default::default())
+        .await
+        .unwrap()
+        .transaction
+        .and_then(|c| c.total)
+        .and_then(|t| t.count)
+        .unwrap();
+
+    // spawn the stream to make sure it can be driven from another task
+    let transactions = tokio::spawn(
+        transaction
+            .get_transactions_stream(Default::default())
+            .try_collect::<Vec<_>>(),
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    assert_eq!(transactions.len() as i64, count);
+
+    client.close().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_get_transaction_categories() {
+    let (config, mut client) = make_client();
+    client.open().await.unwrap();
+
+    let mut transaction = client.transaction(config.test_user1.clone());
+    let res = transaction.get_categories().await.unwrap();
+    assert!(!res.high_level_category_map().is_empty());
+
+    client.close().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_get_holdings() {
+    let (config, mut client) = make_client();
+    client.open().await.unwrap();
+
+    let mut holding = client.holding(config.test_user1.clone());
+    let res = holding
+        .get_holdings(HoldingParams {
+            include: Some("assetClassification"),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    println!("{:#?}", res);
+
+    client.close().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_get_networth() {
+    let (config, mut client) = make_client();
+    client.open().await.unwrap();
+
+    let mut derived = client.derived(config.test_user1.clone());
+    let res = derived
+        .get_networth(DerivedNetworthParams {
+            interval: Some("M"),
+            include: Some("details"),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    println!("{:#?}", res);
+
+    client.close().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_get_providers() {
+    let (config, mut client) = make_client();
+    client.open().await.unwrap();
+
+    let mut provider = client.provider(config.test_user1.clone());
+    let res = provider
+        .get_providers(ProviderParams {
+            name: Some("Dag"),
+            top: Some(5),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    println!("{:#?}", res);
+
+    client.close().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_get_provider_accounts() {
+    let (config, mut client) = make_client();
+    client.open().await.unwrap();
+
+    let mut provider_account = client.provider_account(config.test_user1.clone());
+    let res = provider_account
+        .get_provider_accounts(Default::default())
+        .await
+        .unwrap();
+
+    println!("{:#?}", res);
+
+    client.close().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_get_statements() {
+    let (config, mut client) = make_client();
+    client.open().await.unwrap();
+
+    let mut statement = client.statement(config.test_user1.clone());
+    let res = statement
+        .get_statements(StatementParams {
+            is_latest: Some(true),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    println!("{:#?}", res);
+
+    client.close().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_get_fastlink_config() {
+    let (config, mut client) = make_client();
+    client.open().await.unwrap();
+
+    let mut user = client.user(config.test_user1.clone()).await.unwrap();
+    let res = user
+        .get_fastlink_config(FastLinkParams {
+            flow: Some(FastLinkFlow::Add),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert!(res.access_token.starts_with("Bearer "));
+
+    println!("{}", serde_json::to_string_pretty(&res).unwrap());
+
+    client.close().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_get_event_subscriptions() {
+    let (_, mut client) = make_client();
+    client.open().await.unwrap();
+
+    let mut config = client.config();
+    let res = config.get_event_subscriptions(None).await.unwrap();
+
+    println!("{:#?}", res);
+
+    client.close().await.unwrap();
+}
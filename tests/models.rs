@@ -0,0 +1,110 @@
+use serde_json::json;
+use yodlee_rs::{
+    data_extracts::DataExtractsUserDataResponse,
+    models::{CategorizationRule, CategorizationRuleRequest, RuleClause},
+};
+
+#[test]
+fn test_rule_clause_round_trip() {
+    let req = CategorizationRuleRequest {
+        category_id: 23,
+        priority: None,
+        rule_clause: vec![
+            RuleClause::DescriptionContains("Starbucks".to_string()),
+            RuleClause::AmountGreaterThan(5.0),
+        ],
+    };
+
+    assert_eq!(
+        serde_json::to_value(&req).unwrap(),
+        json!({
+            "categoryId": 23,
+            "ruleClause": [
+                { "field": "description", "operation": "stringContains", "value": "Starbucks" },
+                { "field": "amount", "operation": "numberGreaterThan", "value": 5.0 },
+            ],
+        })
+    );
+}
+
+#[test]
+fn test_rule_clause_from_response() {
+    let rule: CategorizationRule = serde_json::from_value(json!({
+        "userDefinedRuleId": 1,
+        "priority": 2,
+        "ruleClauses": [
+            { "ruleClauseId": 10, "field": "amount", "operation": "numberLessThanEquals", "value": "25" },
+            { "ruleClauseId": 11, "field": "merchant", "operation": "stringEquals", "value": "Acme" },
+        ],
+    }))
+    .unwrap();
+
+    let clauses = rule.rule_clauses.unwrap();
+    assert_eq!(clauses[0].rule_clause_id, Some(10));
+    assert_eq!(clauses[0].clause, RuleClause::AmountLessThanOrEqual(25.0));
+    assert_eq!(
+        clauses[1].clause,
+        RuleClause::Other {
+            field: "merchant".to_string(),
+            operation: "stringEquals".to_string(),
+            value: json!("Acme"),
+        }
+    );
+}
+
+#[test]
+fn test_rules_response() {
+    let rules: Vec<CategorizationRule> =
+        serde_json::from_slice(include_bytes!("payloads/categorization_rules.json")).unwrap();
+
+    assert_eq!(rules[0].transaction_categorisation_id, Some(1000023));
+    let clauses = rules[0].rule_clauses.as_ref().unwrap();
+    assert_eq!(clauses[0].rule_clause_id, Some(10099141));
+    assert_eq!(
+        clauses[0].clause,
+        RuleClause::DescriptionContains("STARBUCKS".to_string())
+    );
+    assert_eq!(
+        clauses[1].clause,
+        RuleClause::AmountGreaterThanOrEqual(5.25)
+    );
+}
+
+#[test]
+fn test_data_extracts_user_data() {
+    let res: DataExtractsUserDataResponse = serde_json::from_value(json!({
+        "totalTransactionsCount": 2,
+        "userData": [{
+            "user": { "loginName": "sbMem5f2a7c1e0b6d41" },
+            "account": [{ "id": 12345678, "CONTAINER": "bank", "isDeleted": false }],
+            "transaction": [
+                { "id": 1001, "accountId": 12345678, "isDeleted": false },
+                { "id": 1002, "accountId": 12345678, "isDeleted": true },
+            ],
+            "holding": [{ "id": 2001, "accountId": 12345679, "isDeleted": true }],
+            "providerAccount": [{ "id": 10886837, "status": "SUCCESS" }],
+        }],
+    }))
+    .unwrap();
+
+    assert_eq!(res.total_transactions_count, Some(2));
+    let user_data = &res.user_data.unwrap()[0];
+    assert_eq!(
+        user_data.user.as_ref().unwrap().login_name.as_deref(),
+        Some("sbMem5f2a7c1e0b6d41")
+    );
+    assert_eq!(
+        user_data.account.as_ref().unwrap()[0].is_deleted,
+        Some(false)
+    );
+    let transactions = user_data.transaction.as_ref().unwrap();
+    assert_eq!(transactions[1].is_deleted, Some(true));
+    assert_eq!(
+        user_data.holding.as_ref().unwrap()[0].is_deleted,
+        Some(true)
+    );
+    assert_eq!(
+        user_data.provider_account.as_ref().unwrap()[0].is_deleted,
+        None
+    );
+}
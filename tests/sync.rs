@@ -0,0 +1,82 @@
+use serde_json::json;
+use yodlee_rs::{
+    models::DataExtractsUserData,
+    sync::{Change, ChangeSet, Record, RecordKey, RecordKind},
+};
+
+fn user_data(value: serde_json::Value) -> DataExtractsUserData {
+    serde_json::from_value(value).unwrap()
+}
+
+fn describe(change: &Change) -> (&'static str, RecordKey) {
+    match change {
+        Change::Upsert(record) => ("upsert", record.key().unwrap()),
+        Change::Delete(key) => ("delete", *key),
+    }
+}
+
+fn key(kind: RecordKind, id: i64) -> RecordKey {
+    RecordKey { kind, id }
+}
+
+#[test]
+fn test_change_set_dedupes() {
+    let mut changes = ChangeSet::new();
+    changes.extend_from_user_data(user_data(json!({
+        "account": [{ "id": 1, "accountName": "Checking" }],
+        "transaction": [
+            { "id": 10, "accountId": 1, "memo": "first" },
+            { "id": 10, "accountId": 1, "memo": "second" },
+            { "accountId": 1, "memo": "no id" },
+        ],
+    })));
+    assert_eq!(changes.len(), 2);
+
+    let changes = changes.into_changes().collect::<Vec<_>>();
+    assert_eq!(
+        changes.iter().map(describe).collect::<Vec<_>>(),
+        vec![
+            ("upsert", key(RecordKind::Account, 1)),
+            ("upsert", key(RecordKind::Transaction, 10)),
+        ]
+    );
+    match &changes[1] {
+        Change::Upsert(Record::Transaction(t)) => assert_eq!(t.memo.as_deref(), Some("second")),
+        change => panic!("unexpected change {change:?}"),
+    }
+}
+
+#[test]
+fn test_change_set_merge_keeps_latest() {
+    let mut earlier = ChangeSet::new();
+    earlier.extend_from_user_data(user_data(json!({
+        "providerAccount": [{ "id": 100 }],
+        "account": [{ "id": 1 }, { "id": 2 }],
+        "holding": [{ "id": 20, "accountId": 2 }],
+    })));
+
+    let mut later = ChangeSet::new();
+    later.extend_from_user_data(user_data(json!({
+        "account": [{ "id": 2, "isDeleted": true }],
+        "holding": [{ "id": 20, "accountId": 2, "isDeleted": true }],
+        "transaction": [{ "id": 1, "accountId": 1 }],
+    })));
+
+    earlier.merge(later);
+
+    // upserts go parents first, deletes children first; account 1 and
+    // transaction 1 are different records despite sharing an id
+    assert_eq!(
+        earlier
+            .into_changes()
+            .map(|c| describe(&c))
+            .collect::<Vec<_>>(),
+        vec![
+            ("upsert", key(RecordKind::ProviderAccount, 100)),
+            ("upsert", key(RecordKind::Account, 1)),
+            ("upsert", key(RecordKind::Transaction, 1)),
+            ("delete", key(RecordKind::Holding, 20)),
+            ("delete", key(RecordKind::Account, 2)),
+        ]
+    );
+}
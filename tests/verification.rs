@@ -0,0 +1,48 @@
+use serde_json::json;
+use yodlee_rs::{models::VerifiedAccount, verification::match_account};
+
+fn verified_account() -> VerifiedAccount {
+    serde_json::from_value(json!({
+        "accountId": 1,
+        "accountNumber": "xxxx4321",
+        "holder": [{ "name": { "first": "Jane", "middle": "Q.", "last": "Doe" } }],
+        "fullAccountNumberList": { "paymentAccountNumber": "000987654321" },
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_match_account() {
+    let account = verified_account();
+
+    let res = match_account(&account, "doe, jane q", "0009-8765-4321");
+    assert!(res.holder_name);
+    assert!(res.account_number);
+    assert!(res.is_match());
+}
+
+#[test]
+fn test_match_account_mismatch() {
+    let account = verified_account();
+
+    let res = match_account(&account, "John Doe", "4321");
+    assert!(!res.holder_name);
+    assert!(!res.account_number);
+    assert!(!res.is_match());
+}
+
+#[test]
+fn test_match_account_partial_name() {
+    let account = verified_account();
+
+    for name in ["Jane Doe", "doe jane", "J. Q. Doe", "Jane Quinn Doe"] {
+        let res = match_account(&account, name, "000987654321");
+        assert!(res.holder_name, "{name} should match");
+        assert!(res.is_match());
+    }
+
+    for name in ["Doe", "Jane Roe", "Jane R. Doe", "J. Doe Jr", ""] {
+        let res = match_account(&account, name, "000987654321");
+        assert!(!res.holder_name, "{name} should not match");
+    }
+}
@@ -0,0 +1,137 @@
+use std::sync::Mutex;
+
+use serde_json::Value;
+use tokio::sync::mpsc;
+use yodlee_rs::{
+    models::EventName,
+    webhook::{Dispatcher, Event, EventHandler, RefreshEvent},
+};
+
+const REFRESH: &[u8] = include_bytes!("payloads/refresh.json");
+const DATA_UPDATES: &[u8] = include_bytes!("payloads/data_updates.json");
+const AUTO_REFRESH_UPDATES: &[u8] = include_bytes!("payloads/auto_refresh_updates.json");
+const LATEST_BALANCE_UPDATES: &[u8] = include_bytes!("payloads/latest_balance_updates.json");
+
+#[test]
+fn test_parse_refresh() {
+    let event = match Event::from_slice(REFRESH).unwrap() {
+        Event::Refresh(event) => event,
+        event => panic!("unexpected event {event:?}"),
+    };
+
+    assert_eq!(event.login_name.as_deref(), Some("sbMem5f2a7c1e0b6d41"));
+    let provider_account = &event.data.unwrap().provider_account.unwrap()[0];
+    assert_eq!(provider_account.id, Some(10886837));
+    assert_eq!(provider_account.status.as_deref(), Some("SUCCESS"));
+}
+
+#[test]
+fn test_parse_data_updates() {
+    let event = match Event::from_slice(DATA_UPDATES).unwrap() {
+        Event::DataUpdates(event) => event,
+        event => panic!("unexpected event {event:?}"),
+    };
+
+    let data = event.data.unwrap();
+    assert_eq!(data.user_count, Some(1));
+    let user_data = &data.user_data.unwrap()[0];
+    let link = &user_data.links.as_ref().unwrap()[0];
+    assert_eq!(link.rel.as_deref(), Some("getUserData"));
+    assert!(link
+        .href
+        .as_deref()
+        .unwrap()
+        .starts_with("dataExtracts/userData"));
+}
+
+#[test]
+fn test_parse_auto_refresh_updates() {
+    let event = match Event::from_slice(AUTO_REFRESH_UPDATES).unwrap() {
+        Event::AutoRefreshUpdates(event) => event,
+        event => panic!("unexpected event {event:?}"),
+    };
+
+    let provider_account = &event.data.unwrap().provider_account.unwrap()[0];
+    let account = &provider_account.account.as_ref().unwrap()[0];
+    assert_eq!(account.id, Some(12345678));
+    assert_eq!(
+        account.auto_refresh.as_ref().unwrap().status.as_deref(),
+        Some("DISABLED")
+    );
+}
+
+#[test]
+fn test_parse_latest_balance_updates() {
+    let event = match Event::from_slice(LATEST_BALANCE_UPDATES).unwrap() {
+        Event::LatestBalanceUpdates(event) => event,
+        event => panic!("unexpected event {event:?}"),
+    };
+
+    let data = event.data.unwrap();
+    assert_eq!(data.provider_account_id, Some(10886837));
+    let account = &data.account.unwrap()[0];
+    assert_eq!(account.refresh_status.as_deref(), Some("SUCCESS"));
+    assert_eq!(account.balance.as_ref().unwrap().amount, 9044.78);
+}
+
+#[test]
+fn test_parse_unknown() {
+    let event = Event::from_slice(br#"{"event":{"info":"SOMETHING.NEW","data":{}}}"#).unwrap();
+    assert!(matches!(event, Event::Unknown(_)));
+    assert_eq!(event.name(), None);
+
+    assert!(Event::from_slice(b"not json").is_err());
+}
+
+#[derive(Debug, Default)]
+struct Recorder {
+    refreshed: Mutex<Vec<Option<String>>>,
+    unknown: Mutex<usize>,
+}
+
+impl EventHandler for Recorder {
+    async fn on_refresh(&self, event: RefreshEvent) {
+        self.refreshed.lock().unwrap().push(event.login_name);
+    }
+
+    async fn on_unknown(&self, _event: Value) {
+        *self.unknown.lock().unwrap() += 1;
+    }
+}
+
+#[tokio::test]
+async fn test_dispatch() {
+    let dispatcher = Dispatcher::new(Recorder::default());
+
+    dispatcher.dispatch(REFRESH).await.unwrap();
+    dispatcher.dispatch(DATA_UPDATES).await.unwrap();
+    dispatcher
+        .dispatch(br#"{"event":{"info":"SOMETHING.NEW"}}"#)
+        .await
+        .unwrap();
+
+    let recorder = dispatcher.handler();
+    assert_eq!(
+        *recorder.refreshed.lock().unwrap(),
+        vec![Some("sbMem5f2a7c1e0b6d41".to_string())]
+    );
+    assert_eq!(*recorder.unknown.lock().unwrap(), 1);
+}
+
+#[tokio::test]
+async fn test_dispatch_to_channel() {
+    let (tx, mut rx) = mpsc::channel(4);
+    let dispatcher = Dispatcher::new(tx);
+
+    dispatcher.dispatch(LATEST_BALANCE_UPDATES).await.unwrap();
+    dispatcher.dispatch(AUTO_REFRESH_UPDATES).await.unwrap();
+
+    assert_eq!(
+        rx.recv().await.unwrap().name(),
+        Some(EventName::LatestBalanceUpdates)
+    );
+    assert_eq!(
+        rx.recv().await.unwrap().name(),
+        Some(EventName::AutoRefreshUpdates)
+    );
+}
@@ -0,0 +1,128 @@
+#![cfg(feature = "webhook-server")]
+
+use std::{sync::Arc, time::Duration};
+
+use tokio::{
+    net::TcpListener,
+    sync::{mpsc, Semaphore},
+    time::{sleep, timeout},
+};
+use yodlee_rs::{
+    models::EventName,
+    webhook::{Event, EventHandler},
+    webhook_server::WebhookServer,
+};
+
+const REFRESH: &[u8] = include_bytes!("payloads/refresh.json");
+const DATA_UPDATES: &[u8] = include_bytes!("payloads/data_updates.json");
+
+async fn start<H: EventHandler + 'static>(server: WebhookServer<H>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/yodlee/events", listener.local_addr().unwrap());
+    tokio::spawn(server.with_path("/yodlee/events").serve(listener));
+    url
+}
+
+async fn post(http_client: &reqwest::Client, url: &str, payload: &'static [u8]) -> u16 {
+    http_client
+        .post(url)
+        .header("Content-Type", "application/json")
+        .body(payload)
+        .send()
+        .await
+        .unwrap()
+        .status()
+        .as_u16()
+}
+
+#[tokio::test]
+async fn test_receive() {
+    let (tx, mut rx) = mpsc::channel(4);
+    let url = start(WebhookServer::new(tx)).await;
+
+    let http_client = reqwest::Client::new();
+    for payload in [REFRESH, DATA_UPDATES] {
+        assert_eq!(post(&http_client, &url, payload).await, 200);
+    }
+
+    assert_eq!(rx.recv().await.unwrap().name(), Some(EventName::Refresh));
+    assert_eq!(
+        rx.recv().await.unwrap().name(),
+        Some(EventName::DataUpdates)
+    );
+
+    assert_eq!(post(&http_client, &url, b"not json").await, 400);
+}
+
+// Slows down refresh events, so later events would overtake them if events
+// were handled concurrently.
+#[derive(Debug)]
+struct Slow(mpsc::Sender<EventName>);
+
+impl EventHandler for Slow {
+    async fn handle(&self, event: Event) {
+        let name = event.name().unwrap();
+        if name == EventName::Refresh {
+            sleep(Duration::from_millis(20)).await;
+        }
+        self.0.send(name).await.unwrap();
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_receive_in_order() {
+    let (tx, mut rx) = mpsc::channel(16);
+    let url = start(WebhookServer::new(Slow(tx))).await;
+
+    let http_client = reqwest::Client::new();
+    let mut sent = Vec::new();
+    for i in 0..8 {
+        let (payload, name) = if i % 2 == 0 {
+            (REFRESH, EventName::Refresh)
+        } else {
+            (DATA_UPDATES, EventName::DataUpdates)
+        };
+        assert_eq!(post(&http_client, &url, payload).await, 200);
+        sent.push(name);
+    }
+
+    let mut received = Vec::new();
+    for _ in 0..sent.len() {
+        received.push(rx.recv().await.unwrap());
+    }
+    assert_eq!(received, sent);
+}
+
+// Holds on to every event until a permit is added.
+#[derive(Debug)]
+struct Blocked(Arc<Semaphore>);
+
+impl EventHandler for Blocked {
+    async fn handle(&self, _event: Event) {
+        self.0.acquire().await.unwrap().forget();
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_receive_back_pressure() {
+    let permits = Arc::new(Semaphore::new(0));
+    let url = start(WebhookServer::new(Blocked(permits.clone())).with_queue_size(1)).await;
+    let http_client = reqwest::Client::new();
+
+    // the first event is taken by the worker, the second fills the queue
+    assert_eq!(post(&http_client, &url, REFRESH).await, 200);
+    assert_eq!(post(&http_client, &url, REFRESH).await, 200);
+
+    // the third has to wait for the handler
+    let third = tokio::spawn({
+        let http_client = http_client.clone();
+        let url = url.clone();
+        async move { post(&http_client, &url, REFRESH).await }
+    });
+    sleep(Duration::from_millis(100)).await;
+    assert!(!third.is_finished());
+
+    permits.add_permits(3);
+    let status = timeout(Duration::from_secs(5), third).await.unwrap();
+    assert_eq!(status.unwrap(), 200);
+}